@@ -3,7 +3,10 @@
 #[openbrush::implementation(PSP22, PSP22Metadata, PSP22Mintable, PSP22Burnable, Ownable, Pausable)]
 #[openbrush::contract]
 pub mod bet_token {
-    use bet_a0::traits::bet_token::*;
+    use bet_a0::{
+        impls::guarded_ownable::{data::GuardedOwnable, GuardedOwnableTraitImpl, *},
+        traits::bet_token::*,
+    };
     use openbrush::{
         contracts::{
             ownable::{OwnableError, *},
@@ -43,6 +46,202 @@ pub mod bet_token {
         metadata: metadata::Data,
         #[storage_field]
         data: Data,
+        #[storage_field]
+        guarded_ownable: GuardedOwnable,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipProposedEvent {
+        #[ink(topic)]
+        owner: Option<AccountId>,
+        #[ink(topic)]
+        pending_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipMovedEvent {
+        #[ink(topic)]
+        old_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianChangedEvent {
+        old_guardian: Option<AccountId>,
+        new_guardian: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryDelayChangedEvent {
+        old_delay: Timestamp,
+        new_delay: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryStartedEvent {
+        #[ink(topic)]
+        guardian: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+        eta: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryCancelledEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// One step transfers are replaced by propose_owner / accept_ownership,
+    /// an owner being recovered cannot hand the contract on
+    #[overrider(Ownable)]
+    #[modifiers(only_owner)]
+    fn transfer_ownership(&mut self, new_owner: Option<AccountId>) -> Result<(), OwnableError> {
+        if GuardedOwnableTraitImpl::get_recovery(self).is_some() {
+            return Err(OwnableError::CallerIsNotOwner);
+        }
+        if let Some(new_owner) = new_owner {
+            self.guarded_ownable.pending_owner.set(&Some(new_owner));
+            GuardedOwnableTraitImpl::_emit_ownership_proposed_event(self, new_owner);
+            Ok(())
+        } else {
+            Err(OwnableError::NewOwnerIsNotSet)
+        }
+    }
+
+    impl GuardedOwnableTraitImpl for BetTokenContract {
+        fn _emit_ownership_proposed_event(&self, pending_owner: AccountId) {
+            self.env().emit_event(OwnershipProposedEvent {
+                owner: Ownable::owner(self),
+                pending_owner,
+            });
+        }
+
+        fn _emit_ownership_moved_event(&self, old_owner: Option<AccountId>, new_owner: AccountId) {
+            self.env().emit_event(OwnershipMovedEvent {
+                old_owner,
+                new_owner,
+            });
+        }
+
+        fn _emit_guardian_changed_event(
+            &self,
+            old_guardian: Option<AccountId>,
+            new_guardian: Option<AccountId>,
+        ) {
+            self.env().emit_event(GuardianChangedEvent {
+                old_guardian,
+                new_guardian,
+            });
+        }
+
+        fn _emit_recovery_delay_changed_event(&self, old_delay: Timestamp, new_delay: Timestamp) {
+            self.env().emit_event(RecoveryDelayChangedEvent {
+                old_delay,
+                new_delay,
+            });
+        }
+
+        fn _emit_recovery_started_event(&self, recovery: OwnerRecovery) {
+            self.env().emit_event(RecoveryStartedEvent {
+                guardian: self.env().caller(),
+                new_owner: recovery.new_owner,
+                eta: recovery.eta,
+            });
+        }
+
+        fn _emit_recovery_cancelled_event(&self, recovery: OwnerRecovery) {
+            self.env().emit_event(RecoveryCancelledEvent {
+                caller: self.env().caller(),
+                new_owner: recovery.new_owner,
+            });
+        }
+    }
+
+    impl GuardedOwnableTrait for BetTokenContract {
+        // Execute function
+        /// Propose a new owner, it takes effect once accepted, not while a recovery is pending - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn propose_owner(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::propose_owner(self, new_owner)
+        }
+
+        /// Accept ownership, not while a recovery is pending - only pending owner
+        #[ink(message)]
+        fn accept_ownership(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::accept_ownership(self)
+        }
+
+        /// Set or remove the guardian, not while a recovery is pending - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::set_guardian(self, guardian)
+        }
+
+        /// Set delay between starting and completing a recovery, one to thirty
+        /// days - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_recovery_delay(
+            &mut self,
+            recovery_delay: Timestamp,
+        ) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::set_recovery_delay(self, recovery_delay)
+        }
+
+        /// Pause the contract - only guardian
+        #[ink(message)]
+        fn guardian_pause(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::guardian_pause(self)
+        }
+
+        /// Start moving ownership to new_owner after the recovery delay - only guardian
+        #[ink(message)]
+        fn start_recovery(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::start_recovery(self, new_owner)
+        }
+
+        /// Cancel a started recovery - only guardian
+        #[ink(message)]
+        fn cancel_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::cancel_recovery(self)
+        }
+
+        /// Transfer ownership once the recovery delay has passed - only guardian
+        #[ink(message)]
+        fn complete_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::complete_recovery(self)
+        }
+
+        // Get Function
+        /// get pending owner
+        #[ink(message)]
+        fn get_pending_owner(&self) -> Option<AccountId> {
+            GuardedOwnableTraitImpl::get_pending_owner(self)
+        }
+
+        /// get guardian
+        #[ink(message)]
+        fn get_guardian(&self) -> Option<AccountId> {
+            GuardedOwnableTraitImpl::get_guardian(self)
+        }
+
+        /// get recovery delay
+        #[ink(message)]
+        fn get_recovery_delay(&self) -> Timestamp {
+            GuardedOwnableTraitImpl::get_recovery_delay(self)
+        }
+
+        /// get started recovery
+        #[ink(message)]
+        fn get_recovery(&self) -> Option<OwnerRecovery> {
+            GuardedOwnableTraitImpl::get_recovery(self)
+        }
     }
 
     impl BetToken for BetTokenContract {
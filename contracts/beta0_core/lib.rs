@@ -1,18 +1,27 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(Ownable, Pausable, Upgradeable)]
+#[openbrush::implementation(Ownable, Pausable, Upgradeable, AccessControl)]
 #[openbrush::contract]
 pub mod beta0_core {
-    use bet_a0::impls::beta0_core::{
-        data::Manager, BetA0CoreTraitImpl, BetInformation, CoreError, *,
+    use bet_a0::impls::{
+        beta0_core::{data::Manager, BetA0CoreTraitImpl, BetInformation, CoreError, *},
+        bonus::{data::Bonus, BonusTraitImpl, *},
+        eligibility::{data::Eligibility, EligibilityTraitImpl, *},
+        guarded_ownable::{data::GuardedOwnable, GuardedOwnableTraitImpl, *},
+        promo::{data::Promo, PromoTraitImpl, *},
+        relay::{data::Relay, RelayTraitImpl, *},
+        responsible_gaming::{data::ResponsibleGaming, ResponsibleGamingTraitImpl, *},
+        session::{data::Session, SessionTraitImpl, *},
+        timelock::{data::Timelock, TimelockTraitImpl, *},
     };
     // use ink::codegen::{EmitEvent, Env};
     use ink::prelude::vec::Vec;
     use openbrush::{
         contracts::{
+            access_control::{AccessControlError, *},
             ownable::{OwnableError, *},
             pausable::{PausableError, *},
-            psp22::PSP22Error,
+            upgradeable::UpgradeableError,
         },
         modifiers,
         traits::{DefaultEnv, Storage, String},
@@ -26,124 +35,1045 @@ pub mod beta0_core {
         #[storage_field]
         pausable: pausable::Data,
         #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
         manager: Manager,
+        #[storage_field]
+        timelock: Timelock,
+        #[storage_field]
+        guarded_ownable: GuardedOwnable,
+        #[storage_field]
+        responsible_gaming: ResponsibleGaming,
+        #[storage_field]
+        eligibility: Eligibility,
+        #[storage_field]
+        bonus: Bonus,
+        #[storage_field]
+        promo: Promo,
+        #[storage_field]
+        relay: Relay,
+        #[storage_field]
+        session: Session,
     }
 
+    /// Emitted when a bet is settled in the player's favour.
+    /// `version` tracks the layout of the game events, `proof` is the
+    /// randomness proof the finalizer submitted with `random_number`.
+    /// A `deferred` win is paid later by `release_deferred_payout`, an
+    /// `is_bonus` win stays locked until the bonus wagering requirement
+    /// is met.
     #[ink(event)]
     pub struct WinEvent {
-        player: Option<AccountId>,
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        bet_id: u64,
+        version: u8,
+        block_number: BlockNumber,
+        placed_at: BlockNumber,
+        finalizer: AccountId,
         is_over: u8,
         random_number: u32,
+        proof: Vec<u8>,
         bet_number: u32,
         bet_amount: Balance,
+        is_bonus: bool,
+        multiplier: u32,
         win_amount: Balance,
+        deferred: bool,
+        reward_tokens: Balance,
     }
 
+    /// Emitted when a bet is settled in the house's favour, with the
+    /// amount of each share of the revenue split.
     #[ink(event)]
     pub struct LoseEvent {
-        player: Option<AccountId>,
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        bet_id: u64,
+        version: u8,
+        block_number: BlockNumber,
+        placed_at: BlockNumber,
+        finalizer: AccountId,
+        is_over: u8,
+        random_number: u32,
+        proof: Vec<u8>,
+        bet_number: u32,
+        bet_amount: Balance,
+        is_bonus: bool,
+        multiplier: u32,
+        revenue_split_id: u32,
+        revenue: Vec<RevenuePayout>,
+        reward_tokens: Balance,
+    }
+
+    /// Emitted when a roll equal to `bet_number` refunds part or all of
+    /// the stake under the tie policy.
+    #[ink(event)]
+    pub struct PushEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        bet_id: u64,
+        version: u8,
+        block_number: BlockNumber,
+        placed_at: BlockNumber,
+        finalizer: AccountId,
         is_over: u8,
         random_number: u32,
+        proof: Vec<u8>,
         bet_number: u32,
         bet_amount: Balance,
+        is_bonus: bool,
+        refund_amount: Balance,
+        revenue_split_id: u32,
+        revenue: Vec<RevenuePayout>,
+        reward_tokens: Balance,
     }
 
+    /// Emitted when a bet is placed. `multiplier` is the payout rate at
+    /// the time of play with any promo boost, `is_bonus` bets are paid
+    /// with bonus credits.
     #[ink(event)]
     pub struct PlayEvent {
-        player: Option<AccountId>,
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        bet_id: u64,
+        version: u8,
+        block_number: BlockNumber,
         is_over: u8,
         bet_number: u32,
         bet_amount: Balance,
+        is_bonus: bool,
+        multiplier: u32,
     }
 
-    impl BetA0CoreTraitImpl for BetA0CoreContract {}
+    #[ink(event)]
+    pub struct ConfigChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        param: ConfigParam,
+        old_value: u32,
+        new_value: u32,
+    }
 
-    impl BetA0CoreTrait for BetA0CoreContract {
-        // Execute function
-        /// Function changes state
-        #[ink(message)]
-        fn change_state(&mut self) -> Result<(), PausableError> {
-            BetA0CoreTraitImpl::change_state(self)
+    #[ink(event)]
+    pub struct AccountConfigChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        param: ConfigParam,
+        old_value: AccountId,
+        new_value: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RatesChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        old_over_rates: Vec<u32>,
+        new_over_rates: Vec<u32>,
+        old_under_rates: Vec<u32>,
+        new_under_rates: Vec<u32>,
+    }
+
+    #[ink(event)]
+    pub struct WithdrawFeeEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        value: Balance,
+        old_balance: Balance,
+        new_balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct WithdrawTokenEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        value: Balance,
+        old_balance: Balance,
+        new_balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct TransferTokenToPoolEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        #[ink(topic)]
+        pool: AccountId,
+        amount: Balance,
+        old_balance: Balance,
+        new_balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ChangeQueuedEvent {
+        #[ink(topic)]
+        id: u64,
+        #[ink(topic)]
+        caller: AccountId,
+        action: TimelockAction,
+        eta: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct ChangeExecutedEvent {
+        #[ink(topic)]
+        id: u64,
+        #[ink(topic)]
+        caller: AccountId,
+        action: TimelockAction,
+    }
+
+    #[ink(event)]
+    pub struct ChangeCancelledEvent {
+        #[ink(topic)]
+        id: u64,
+        #[ink(topic)]
+        caller: AccountId,
+        action: TimelockAction,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipProposedEvent {
+        #[ink(topic)]
+        owner: Option<AccountId>,
+        #[ink(topic)]
+        pending_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipMovedEvent {
+        #[ink(topic)]
+        old_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianChangedEvent {
+        old_guardian: Option<AccountId>,
+        new_guardian: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryDelayChangedEvent {
+        old_delay: Timestamp,
+        new_delay: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryStartedEvent {
+        #[ink(topic)]
+        guardian: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+        eta: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryCancelledEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    /// One step transfers are replaced by propose_owner / accept_ownership,
+    /// an owner being recovered cannot hand the contract on
+    #[overrider(Ownable)]
+    #[modifiers(only_owner)]
+    fn transfer_ownership(&mut self, new_owner: Option<AccountId>) -> Result<(), OwnableError> {
+        if GuardedOwnableTraitImpl::get_recovery(self).is_some() {
+            return Err(OwnableError::CallerIsNotOwner);
+        }
+        if let Some(new_owner) = new_owner {
+            self.guarded_ownable.pending_owner.set(&Some(new_owner));
+            GuardedOwnableTraitImpl::_emit_ownership_proposed_event(self, new_owner);
+            Ok(())
+        } else {
+            Err(OwnableError::NewOwnerIsNotSet)
         }
+    }
 
-        /// tranfer token to pool
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn tranfer_token_to_pool(
-            &mut self,
-            pool: AccountId,
+    /// Upgrades are refused while bets are pending or stakes escrowed
+    #[overrider(Upgradeable)]
+    #[modifiers(only_owner)]
+    fn set_code_hash(&mut self, new_code_hash: Hash) -> Result<(), UpgradeableError> {
+        self._check_upgradable()
+            .map_err(|_| UpgradeableError::Custom(String::from("O::Bets Pending")))?;
+        self.env()
+            .set_code_hash(&new_code_hash)
+            .map_err(|_| UpgradeableError::SetCodeHashFailed)
+    }
+
+    #[ink(event)]
+    pub struct MigratedEvent {
+        from_version: u32,
+        to_version: u32,
+    }
+
+    /// Emitted when house losses over the window pass the limit and betting stops
+    #[ink(event)]
+    pub struct CircuitBreakerTrippedEvent {
+        window_start: BlockNumber,
+        house_loss: Balance,
+        bankroll: Balance,
+    }
+
+    #[ink(event)]
+    pub struct CircuitBreakerResetEvent {
+        #[ink(topic)]
+        caller: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct CircuitBreakerConfigChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        window: BlockNumber,
+        max_loss_ratio: u32,
+    }
+
+    #[ink(event)]
+    pub struct DepositEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+        balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct WithdrawEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+        balance: Balance,
+    }
+
+    #[ink(event)]
+    pub struct BonusGrantedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        credits: Balance,
+        wagering_required: Balance,
+        expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct BonusWageredEvent {
+        #[ink(topic)]
+        player: AccountId,
+        wagered: Balance,
+        wagering_required: Balance,
+    }
+
+    #[ink(event)]
+    pub struct BonusUnlockedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct BonusForfeitedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        credits: Balance,
+        locked_winnings: Balance,
+    }
+
+    #[ink(event)]
+    pub struct PromoSignerChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        old_signer: Option<[u8; 33]>,
+        new_signer: Option<[u8; 33]>,
+    }
+
+    #[ink(event)]
+    pub struct PromoRedeemedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        code_id: u32,
+        reward: PromoReward,
+    }
+
+    /// Emitted after the PlayEvent of a bet submitted with `play_signed`
+    #[ink(event)]
+    pub struct BetRelayedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        relayer: AccountId,
+        nonce: u64,
+        relayer_fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct NonceInvalidatedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        nonce: u64,
+    }
+
+    #[ink(event)]
+    pub struct SessionKeyProposedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        key: AccountId,
+        expires_at: Timestamp,
+        max_total_stake: Balance,
+        allow_over: bool,
+        allow_under: bool,
+    }
+
+    #[ink(event)]
+    pub struct SessionKeyRegisteredEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        key: AccountId,
+        expires_at: Timestamp,
+        max_total_stake: Balance,
+        allow_over: bool,
+        allow_under: bool,
+    }
+
+    #[ink(event)]
+    pub struct SessionKeyRevokedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        key: AccountId,
+    }
+
+    /// Emitted when winnings or a refund are credited to the player balance
+    #[ink(event)]
+    pub struct CreditedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+        claimable: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ClaimedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct AutoPushChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        auto_push: bool,
+    }
+
+    /// Emitted once per update_config with the fields that were set
+    #[ink(event)]
+    pub struct ConfigUpdatedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        update: GameConfigUpdate,
+    }
+
+    #[ink(event)]
+    pub struct RevenueSplitChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        revenue_split_id: u32,
+        revenue_split: Vec<RevenueShare>,
+    }
+
+    #[ink(event)]
+    pub struct TiePolicyChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        old_value: TiePolicy,
+        new_value: TiePolicy,
+    }
+
+    #[ink(event)]
+    pub struct RateLimitsChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        rate_limits: RateLimits,
+    }
+
+    #[ink(event)]
+    pub struct EmergencyShutdownEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        grace_period: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct BetRefundedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        #[ink(topic)]
+        bet_id: u64,
+        bet_amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OperationPausedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        operation: PausableOperation,
+        paused: bool,
+    }
+
+    #[ink(event)]
+    pub struct EligibilityChangedEvent {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        caller: AccountId,
+        list: EligibilityList,
+        listed: bool,
+    }
+
+    #[ink(event)]
+    pub struct AttestationSubmittedEvent {
+        #[ink(topic)]
+        account: AccountId,
+        expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct EligibilitySettingChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        setting: EligibilitySetting,
+        old_value: bool,
+        new_value: bool,
+    }
+
+    /// Emitted when the attestation signer changes, earlier attestations
+    /// no longer count
+    #[ink(event)]
+    pub struct AttestationSignerChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        old_signer: Option<[u8; 33]>,
+        new_signer: Option<[u8; 33]>,
+    }
+
+    #[ink(event)]
+    pub struct LimitsChangedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        limits: PlayerLimits,
+    }
+
+    #[ink(event)]
+    pub struct LimitsIncreaseQueuedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        limits: PlayerLimits,
+        effective_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct SelfExcludedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        until: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct BalanceConfigChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        param: ConfigParam,
+        old_value: Balance,
+        new_value: Balance,
+    }
+
+    /// Emitted when a revenue share could not be sent, it is credited to
+    /// the balance of the recipient instead
+    #[ink(event)]
+    pub struct RevenueTransferFailedEvent {
+        #[ink(topic)]
+        recipient: AccountId,
+        kind: RevenueKind,
+        amount: Balance,
+    }
+
+    /// Emitted when the reward tokens of a settled bet could not be sent,
+    /// the settlement itself goes through
+    #[ink(event)]
+    pub struct RewardFailedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        bet_amount: Balance,
+        error: CoreError,
+    }
+
+    #[ink(event)]
+    pub struct TimestampConfigChangedEvent {
+        #[ink(topic)]
+        caller: AccountId,
+        param: ConfigParam,
+        old_value: Timestamp,
+        new_value: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct PayoutDeferredEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct DeferredPayoutReleasedEvent {
+        #[ink(topic)]
+        player: AccountId,
+        amount: Balance,
+    }
+
+    impl BetA0CoreTraitImpl for BetA0CoreContract {
+        fn _get_multiplier_boost(&self, player: AccountId) -> u32 {
+            PromoTraitImpl::get_multiplier_boost(self, player)
+        }
+
+        fn _emit_revenue_transfer_failed_event(
+            &self,
+            kind: RevenueKind,
+            recipient: AccountId,
             amount: Balance,
-        ) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::tranfer_token_to_pool(self, pool, amount)
+        ) {
+            self.env().emit_event(RevenueTransferFailedEvent {
+                recipient,
+                kind,
+                amount,
+            });
         }
 
-        /// reward token by bet pool
-        #[ink(message)]
-        fn reward_token_to_player(
-            &mut self,
+        fn _emit_reward_failed_event(
+            &self,
             player: AccountId,
             bet_amount: Balance,
-        ) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::reward_token_to_player(self, player, bet_amount)
+            error: CoreError,
+        ) {
+            self.env().emit_event(RewardFailedEvent {
+                player,
+                bet_amount,
+                error,
+            });
         }
 
-        /// Function reward token
-        #[ink(message)]
-        fn reward_token(
-            &mut self,
-            player: AccountId,
-            bet_amount: Balance,
-        ) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::reward_token(self, player, bet_amount)
+        fn _emit_timestamp_config_changed_event(
+            &self,
+            param: ConfigParam,
+            old_value: Timestamp,
+            new_value: Timestamp,
+        ) {
+            self.env().emit_event(TimestampConfigChangedEvent {
+                caller: self.env().caller(),
+                param,
+                old_value,
+                new_value,
+            });
         }
 
-        /// Withdraw Fees - only Owner
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn withdraw_fee(&mut self, value: Balance) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::withdraw_fee(self, value)
+        fn _emit_balance_config_changed_event(
+            &self,
+            param: ConfigParam,
+            old_value: Balance,
+            new_value: Balance,
+        ) {
+            self.env().emit_event(BalanceConfigChangedEvent {
+                caller: self.env().caller(),
+                param,
+                old_value,
+                new_value,
+            });
         }
 
-        /// Withdraw Token - only Owner
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn withdraw_token(&mut self, value: Balance) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::withdraw_token(self, value)
+        fn _emit_payout_deferred_event(&self, player: AccountId, amount: Balance) {
+            self.env()
+                .emit_event(PayoutDeferredEvent { player, amount });
         }
 
-        // Set function
-        /// Set min number over roll
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_min_number_over_roll(&mut self, min_over_number: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_min_number_over_roll(self, min_over_number)
+        fn _emit_deferred_payout_released_event(&self, player: AccountId, amount: Balance) {
+            self.env()
+                .emit_event(DeferredPayoutReleasedEvent { player, amount });
         }
 
-        /// Set max number over roll
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_max_number_over_roll(&mut self, max_over_number: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_max_number_over_roll(self, max_over_number)
+        fn _emit_operation_paused_event(&self, operation: PausableOperation, paused: bool) {
+            self.env().emit_event(OperationPausedEvent {
+                caller: self.env().caller(),
+                operation,
+                paused,
+            });
         }
 
-        /// Set min number under roll
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_min_number_under_roll(&mut self, min_under_number: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_min_number_under_roll(self, min_under_number)
+        fn _emit_circuit_breaker_tripped_event(
+            &self,
+            window_start: BlockNumber,
+            house_loss: Balance,
+            bankroll: Balance,
+        ) {
+            self.env().emit_event(CircuitBreakerTrippedEvent {
+                window_start,
+                house_loss,
+                bankroll,
+            });
         }
 
-        /// Set max number under roll
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_max_number_under_roll(&mut self, max_under_number: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_max_number_under_roll(self, max_under_number)
+        fn _emit_circuit_breaker_reset_event(&self) {
+            self.env().emit_event(CircuitBreakerResetEvent {
+                caller: self.env().caller(),
+            });
         }
 
-        /// Set over_rates and discount rate - Only Owner 2 vectors same size
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_rates(
+        fn _emit_circuit_breaker_config_changed_event(
+            &self,
+            window: BlockNumber,
+            max_loss_ratio: u32,
+        ) {
+            self.env().emit_event(CircuitBreakerConfigChangedEvent {
+                caller: self.env().caller(),
+                window,
+                max_loss_ratio,
+            });
+        }
+
+        fn _emit_credited_event(&self, player: AccountId, amount: Balance, claimable: Balance) {
+            self.env().emit_event(CreditedEvent {
+                player,
+                amount,
+                claimable,
+            });
+        }
+
+        fn _emit_deposit_event(&self, player: AccountId, amount: Balance, balance: Balance) {
+            self.env().emit_event(DepositEvent {
+                player,
+                amount,
+                balance,
+            });
+        }
+
+        fn _emit_withdraw_event(&self, player: AccountId, amount: Balance, balance: Balance) {
+            self.env().emit_event(WithdrawEvent {
+                player,
+                amount,
+                balance,
+            });
+        }
+
+        fn _emit_claimed_event(&self, player: AccountId, amount: Balance) {
+            self.env().emit_event(ClaimedEvent { player, amount });
+        }
+
+        fn _emit_auto_push_changed_event(&self, auto_push: bool) {
+            self.env().emit_event(AutoPushChangedEvent {
+                caller: self.env().caller(),
+                auto_push,
+            });
+        }
+
+        fn _emit_config_updated_event(&self, update: GameConfigUpdate) {
+            self.env().emit_event(ConfigUpdatedEvent {
+                caller: self.env().caller(),
+                update,
+            });
+        }
+
+        fn _emit_revenue_split_changed_event(
+            &self,
+            revenue_split_id: u32,
+            revenue_split: Vec<RevenueShare>,
+        ) {
+            self.env().emit_event(RevenueSplitChangedEvent {
+                caller: self.env().caller(),
+                revenue_split_id,
+                revenue_split,
+            });
+        }
+
+        fn _emit_tie_policy_changed_event(&self, old_value: TiePolicy, new_value: TiePolicy) {
+            self.env().emit_event(TiePolicyChangedEvent {
+                caller: self.env().caller(),
+                old_value,
+                new_value,
+            });
+        }
+
+        fn _emit_rate_limits_changed_event(&self, rate_limits: RateLimits) {
+            self.env().emit_event(RateLimitsChangedEvent {
+                caller: self.env().caller(),
+                rate_limits,
+            });
+        }
+
+        fn _emit_emergency_shutdown_event(&self, grace_period: Timestamp) {
+            self.env().emit_event(EmergencyShutdownEvent {
+                caller: self.env().caller(),
+                grace_period,
+            });
+        }
+
+        fn _emit_bet_refunded_event(&self, player: AccountId, bet_info: BetInformation) {
+            self.env().emit_event(BetRefundedEvent {
+                player,
+                bet_id: bet_info.bet_id,
+                bet_amount: bet_info.bet_amount,
+            });
+        }
+
+        fn _emit_migrated_event(&self, from_version: u32, to_version: u32) {
+            self.env().emit_event(MigratedEvent {
+                from_version,
+                to_version,
+            });
+        }
+
+        fn _emit_config_changed_event(&self, param: ConfigParam, old_value: u32, new_value: u32) {
+            self.env().emit_event(ConfigChangedEvent {
+                caller: self.env().caller(),
+                param,
+                old_value,
+                new_value,
+            });
+        }
+
+        fn _emit_account_config_changed_event(
+            &self,
+            param: ConfigParam,
+            old_value: AccountId,
+            new_value: AccountId,
+        ) {
+            self.env().emit_event(AccountConfigChangedEvent {
+                caller: self.env().caller(),
+                param,
+                old_value,
+                new_value,
+            });
+        }
+
+        fn _emit_rates_changed_event(
+            &self,
+            old_over_rates: Vec<u32>,
+            new_over_rates: Vec<u32>,
+            old_under_rates: Vec<u32>,
+            new_under_rates: Vec<u32>,
+        ) {
+            self.env().emit_event(RatesChangedEvent {
+                caller: self.env().caller(),
+                old_over_rates,
+                new_over_rates,
+                old_under_rates,
+                new_under_rates,
+            });
+        }
+
+        fn _emit_withdraw_fee_event(
+            &self,
+            value: Balance,
+            old_balance: Balance,
+            new_balance: Balance,
+        ) {
+            self.env().emit_event(WithdrawFeeEvent {
+                caller: self.env().caller(),
+                value,
+                old_balance,
+                new_balance,
+            });
+        }
+
+        fn _emit_withdraw_token_event(
+            &self,
+            value: Balance,
+            old_balance: Balance,
+            new_balance: Balance,
+        ) {
+            self.env().emit_event(WithdrawTokenEvent {
+                caller: self.env().caller(),
+                value,
+                old_balance,
+                new_balance,
+            });
+        }
+
+        fn _emit_transfer_token_to_pool_event(
+            &self,
+            pool: AccountId,
+            amount: Balance,
+            old_balance: Balance,
+            new_balance: Balance,
+        ) {
+            self.env().emit_event(TransferTokenToPoolEvent {
+                caller: self.env().caller(),
+                pool,
+                amount,
+                old_balance,
+                new_balance,
+            });
+        }
+    }
+
+    impl BetA0CoreTrait for BetA0CoreContract {
+        // Execute function
+        /// Function changes state - only PAUSER
+        #[ink(message)]
+        fn change_state(&mut self) -> Result<(), PausableError> {
+            BetA0CoreTraitImpl::change_state(self)
+        }
+
+        /// Pause or resume a single operation - only PAUSER
+        #[ink(message)]
+        #[modifiers(only_role(PAUSER))]
+        fn set_operation_paused(
+            &mut self,
+            operation: PausableOperation,
+            paused: bool,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_operation_paused(self, operation, paused)
+        }
+
+        /// Run the storage migration steps after an upgrade, the caller gets
+        /// the roles of a new deployment - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn migrate(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::migrate(self)
+        }
+
+        /// Stop new bets for good and let players reclaim pending stakes - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn emergency_shutdown(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::emergency_shutdown(self)
+        }
+
+        /// Reclaim the stake of a pending bet during emergency shutdown
+        #[ink(message)]
+        fn refund_bet(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::refund_bet(self)
+        }
+
+        /// Let bets in again after the circuit breaker tripped - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn reset_circuit_breaker(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::reset_circuit_breaker(self)
+        }
+
+        /// Pay out deferred wins up to what the block payout cap allows, the
+        /// rest stays deferred for a later block
+        #[ink(message)]
+        fn release_deferred_payout(&mut self, player: AccountId) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::release_deferred_payout(self, player)
+        }
+
+        /// Withdraw the whole caller balance
+        #[ink(message)]
+        fn claim(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::claim(self)
+        }
+
+        /// Add the transferred native value to the caller balance, stakes and payouts are in the native token only
+        #[ink(message)]
+        #[ink(payable)]
+        fn deposit(&mut self) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::deposit(self)
+        }
+
+        /// Withdraw amount from the caller balance
+        #[ink(message)]
+        fn withdraw(&mut self, amount: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::withdraw(self, amount)
+        }
+
+        /// tranfer token to pool
+        #[ink(message)]
+        #[modifiers(only_role(TREASURER))]
+        fn tranfer_token_to_pool(
+            &mut self,
+            pool: AccountId,
+            amount: Balance,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::tranfer_token_to_pool(self, pool, amount)
+        }
+
+        /// reward token by bet pool
+        #[ink(message)]
+        #[modifiers(only_role(FINALIZER))]
+        fn reward_token_to_player(
+            &mut self,
+            player: AccountId,
+            bet_amount: Balance,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::reward_token_to_player(self, player, bet_amount)
+        }
+
+        /// Function reward token
+        #[ink(message)]
+        #[modifiers(only_role(FINALIZER))]
+        fn reward_token(
+            &mut self,
+            player: AccountId,
+            bet_amount: Balance,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::reward_token(self, player, bet_amount)
+        }
+
+        /// Withdraw Fees, escrowed stakes and balances owed to players stay - only TREASURER
+        #[ink(message)]
+        #[modifiers(only_role(TREASURER))]
+        fn withdraw_fee(&mut self, value: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::withdraw_fee(self, value)
+        }
+
+        /// Withdraw Token - only TREASURER
+        #[ink(message)]
+        #[modifiers(only_role(TREASURER))]
+        fn withdraw_token(&mut self, value: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::withdraw_token(self, value)
+        }
+
+        // Set function
+        /// Set min number over roll
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_min_number_over_roll(&mut self, min_over_number: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_min_number_over_roll(self, min_over_number)
+        }
+
+        /// Set max number over roll
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_max_number_over_roll(&mut self, max_over_number: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_max_number_over_roll(self, max_over_number)
+        }
+
+        /// Set min number under roll
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_min_number_under_roll(&mut self, min_under_number: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_min_number_under_roll(self, min_under_number)
+        }
+
+        /// Set max number under roll
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_max_number_under_roll(&mut self, max_under_number: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_max_number_under_roll(self, max_under_number)
+        }
+
+        /// Set over_rates and discount rate - Only CONFIGURATOR 2 vectors same size
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_rates(
             &mut self,
             over_rates: Vec<u32>,
             under_rates: Vec<u32>,
@@ -151,174 +1081,1162 @@ pub mod beta0_core {
             BetA0CoreTraitImpl::set_rates(self, over_rates, under_rates)
         }
 
+        /// Set how a roll equal to bet_number is settled - only CONFIGURATOR
+        /// Fails once a timelock delay is set, use queue_change instead
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_tie_policy(&mut self, tie_policy: TiePolicy) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_tie_policy(self, tie_policy)
+        }
+
         /// Set new psp22 address
         #[ink(message)]
-        #[modifiers(only_owner)]
+        #[modifiers(only_role(CONFIGURATOR))]
         fn set_bet_token_address(&mut self, bet_token_address: AccountId) -> Result<(), CoreError> {
             BetA0CoreTraitImpl::set_bet_token_address(self, bet_token_address)
         }
 
-        /// Set new token ratio
+        /// Set new token ratio
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_token_ratio(&mut self, token_ratio: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_token_ratio(self, token_ratio)
+        }
+
+        /// Set max bet ratio
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_max_bet_ratio(&mut self, max_bet_ratio: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_max_bet_ratio(self, max_bet_ratio)
+        }
+
+        /// Set revenue_ratio, replaces the revenue split with reward_pool and general_pool
+        /// Fails once a timelock delay is set, use queue_change instead
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_revenue_ratio(&mut self, revenue_ratio: u32) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_revenue_ratio(self, revenue_ratio)
+        }
+
+        /// Set how losing stakes are split, shares in bps must sum to 10000 - only CONFIGURATOR
+        /// Fails once a timelock delay is set, use queue_change instead
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_revenue_split(&mut self, revenue_split: Vec<RevenueShare>) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_revenue_split(self, revenue_split)
+        }
+
+        /// Set reward_pool, Reward shares of the revenue split paid to the old
+        /// pool move to the new one, bets already placed keep their split
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_reward_pool(&mut self, reward_pool: AccountId) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_reward_pool(self, reward_pool)
+        }
+
+        /// Set general_pool, Treasury shares of the revenue split paid to the
+        /// old pool move to the new one, bets already placed keep their split
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_general_pool(&mut self, general_pool: AccountId) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_general_pool(self, general_pool)
+        }
+
+        /// Set bet_pool
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_bet_pool(&mut self, bet_pool: AccountId) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_bet_pool(self, bet_pool)
+        }
+
+        /// Set admin id, the FINALIZER role moves to the new admin
+        #[ink(message)]
+        #[modifiers(only_role(DEFAULT_ADMIN_ROLE))]
+        fn set_admin_account(&mut self, admin_account: AccountId) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_admin_account(self, admin_account)
+        }
+
+        /// Set grace period after which the bankroll can be withdrawn in emergency shutdown,
+        /// at least one day and fixed once shutdown starts
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_emergency_grace_period(
+            &mut self,
+            emergency_grace_period: Timestamp,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_emergency_grace_period(self, emergency_grace_period)
+        }
+
+        /// Set minimum bet - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_min_bet(&mut self, min_bet: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_min_bet(self, min_bet)
+        }
+
+        /// Set maximum payout of a single bet, 0 for no cap - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_max_payout(&mut self, max_payout: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_max_payout(self, max_payout)
+        }
+
+        /// Set maximum total payout per block, 0 for no cap - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_max_block_payout(&mut self, max_block_payout: Balance) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_max_block_payout(self, max_block_payout)
+        }
+
+        /// Apply many settings at once, validated together - only CONFIGURATOR
+        /// Fails on timelocked fields once a timelock delay is set, use queue_change instead
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn update_config(&mut self, update: GameConfigUpdate) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::update_config(self, update)
+        }
+
+        /// Set circuit breaker window in blocks and loss limit in bps of bankroll, 0 limit disables it - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_circuit_breaker(
+            &mut self,
+            window: BlockNumber,
+            max_loss_ratio: u32,
+        ) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_circuit_breaker(self, window, max_loss_ratio)
+        }
+
+        /// Set bet rate limits, 0 disables a limit - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_rate_limits(&mut self, rate_limits: RateLimits) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_rate_limits(self, rate_limits)
+        }
+
+        /// Send winnings of bets paid with a transfer straight to players and credit their balance only if the transfer fails - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_auto_push(&mut self, auto_push: bool) -> Result<(), CoreError> {
+            BetA0CoreTraitImpl::set_auto_push(self, auto_push)
+        }
+
+        // Get Function
+        /// get the whole game configuration in one call
+        #[ink(message)]
+        fn get_config(&self) -> GameConfig {
+            BetA0CoreTraitImpl::get_config(self)
+        }
+
+        /// Quote a bet of the caller without placing it, same checks and arithmetic as play and finalize
+        /// including a promo boost waiting for the caller's next bet
+        #[ink(message)]
+        fn quote(&self, is_over: u8, bet_number: u32, amount: Balance) -> BetQuote {
+            BetA0CoreTraitImpl::quote(self, is_over, bet_number, amount)
+        }
+
+        /// get min number over roll
+        #[ink(message)]
+        fn get_min_number_over_roll(&self) -> u32 {
+            BetA0CoreTraitImpl::get_min_number_over_roll(self)
+        }
+
+        /// get max number over roll
+        #[ink(message)]
+        fn get_max_number_over_roll(&self) -> u32 {
+            BetA0CoreTraitImpl::get_max_number_over_roll(self)
+        }
+
+        /// get min number under roll
+        #[ink(message)]
+        fn get_min_number_under_roll(&self) -> u32 {
+            BetA0CoreTraitImpl::get_min_number_under_roll(self)
+        }
+
+        /// get max number under roll
+        #[ink(message)]
+        fn get_max_number_under_roll(&self) -> u32 {
+            BetA0CoreTraitImpl::get_max_number_under_roll(self)
+        }
+
+        /// Get token ratio
+        #[ink(message)]
+        fn get_token_ratio(&self) -> u32 {
+            BetA0CoreTraitImpl::get_token_ratio(self)
+        }
+
+        /// get revenue ratio
+        #[ink(message)]
+        fn get_revenue_ratio(&self) -> u32 {
+            BetA0CoreTraitImpl::get_token_ratio(self)
+        }
+
+        /// get id of the current revenue split
+        #[ink(message)]
+        fn get_revenue_split_id(&self) -> u32 {
+            BetA0CoreTraitImpl::get_revenue_split_id(self)
+        }
+
+        /// get revenue split by id, bets settle with the split of their play
+        #[ink(message)]
+        fn get_revenue_split(&self, revenue_split_id: u32) -> Vec<RevenueShare> {
+            BetA0CoreTraitImpl::get_revenue_split(self, revenue_split_id)
+        }
+
+        /// get reward pool
+        #[ink(message)]
+        fn get_reward_pool(&self) -> AccountId {
+            BetA0CoreTraitImpl::get_reward_pool(self)
+        }
+
+        /// get general pool
+        #[ink(message)]
+        fn get_general_pool(&self) -> AccountId {
+            BetA0CoreTraitImpl::get_general_pool(self)
+        }
+
+        /// get bet pool
+        #[ink(message)]
+        fn get_bet_pool(&self) -> AccountId {
+            BetA0CoreTraitImpl::get_bet_pool(self)
+        }
+
+        /// Get psp22 address
+        #[ink(message)]
+        fn bet_token_address(&self) -> AccountId {
+            BetA0CoreTraitImpl::bet_token_address(self)
+        }
+
+        /// Get Over Rates
+        #[ink(message)]
+        fn get_over_rates(&self) -> Vec<u32> {
+            BetA0CoreTraitImpl::get_over_rates(self)
+        }
+
+        /// Get Under Rates
+        #[ink(message)]
+        fn get_under_rates(&self) -> Vec<u32> {
+            BetA0CoreTraitImpl::get_under_rates(self)
+        }
+
+        /// Get Max Bet
+        #[ink(message)]
+        fn get_max_bet_ratio(&self) -> u32 {
+            BetA0CoreTraitImpl::get_max_bet_ratio(self)
+        }
+
+        #[ink(message)]
+        fn get_max_bet(&self) -> u128 {
+            BetA0CoreTraitImpl::get_max_bet(self)
+        }
+
+        /// get contract token balance
+        #[ink(message)]
+        fn get_token_balance(&self) -> Balance {
+            BetA0CoreTraitImpl::get_token_balance(self)
+        }
+
+        /// get token balance pool
+        #[ink(message)]
+        fn get_token_balance_pool(&self, pool: AccountId) -> Balance {
+            BetA0CoreTraitImpl::get_token_balance_pool(self, pool)
+        }
+
+        /// Is bet exist
+        #[ink(message)]
+        fn is_bet_available(&self, player: AccountId) -> bool {
+            BetA0CoreTraitImpl::is_bet_available(self, player)
+        }
+
+        /// get admin id
+        #[ink(message)]
+        fn get_admin_account(&self) -> AccountId {
+            BetA0CoreTraitImpl::get_admin_account(self)
+        }
+
+        /// get bet
+        #[ink(message)]
+        fn get_bet(&self, player: AccountId) -> Option<BetInformation> {
+            BetA0CoreTraitImpl::get_bet(self, player)
+        }
+
+        /// get storage version
+        #[ink(message)]
+        fn get_storage_version(&self) -> u32 {
+            BetA0CoreTraitImpl::get_storage_version(self)
+        }
+
+        /// Is operation paused by its own switch
+        #[ink(message)]
+        fn is_operation_paused(&self, operation: PausableOperation) -> bool {
+            BetA0CoreTraitImpl::is_operation_paused(self, operation)
+        }
+
+        /// get operations paused by their own switch
+        #[ink(message)]
+        fn get_paused_operations(&self) -> Vec<PausableOperation> {
+            BetA0CoreTraitImpl::get_paused_operations(self)
+        }
+
+        /// Is emergency shutdown
+        #[ink(message)]
+        fn is_emergency_shutdown(&self) -> bool {
+            BetA0CoreTraitImpl::is_emergency_shutdown(self)
+        }
+
+        /// get emergency shutdown time
+        #[ink(message)]
+        fn get_emergency_shutdown_at(&self) -> Option<Timestamp> {
+            BetA0CoreTraitImpl::get_emergency_shutdown_at(self)
+        }
+
+        /// get emergency grace period
+        #[ink(message)]
+        fn get_emergency_grace_period(&self) -> Timestamp {
+            BetA0CoreTraitImpl::get_emergency_grace_period(self)
+        }
+
+        /// get number of pending bets, bets placed before storage version 1
+        /// are not counted
+        #[ink(message)]
+        fn get_pending_bets(&self) -> u32 {
+            BetA0CoreTraitImpl::get_pending_bets(self)
+        }
+
+        /// get total stake of pending bets
+        #[ink(message)]
+        fn get_escrowed_stakes(&self) -> Balance {
+            BetA0CoreTraitImpl::get_escrowed_stakes(self)
+        }
+
+        /// get minimum bet
+        #[ink(message)]
+        fn get_min_bet(&self) -> Balance {
+            BetA0CoreTraitImpl::get_min_bet(self)
+        }
+
+        /// get maximum payout of a single bet
+        #[ink(message)]
+        fn get_max_payout(&self) -> Balance {
+            BetA0CoreTraitImpl::get_max_payout(self)
+        }
+
+        /// get maximum total payout per block
+        #[ink(message)]
+        fn get_max_block_payout(&self) -> Balance {
+            BetA0CoreTraitImpl::get_max_block_payout(self)
+        }
+
+        /// get total paid out in the current block
+        #[ink(message)]
+        fn get_block_payout(&self) -> Balance {
+            BetA0CoreTraitImpl::get_block_payout(self)
+        }
+
+        /// get deferred payout of player
+        #[ink(message)]
+        fn get_deferred_payout(&self, player: AccountId) -> Balance {
+            BetA0CoreTraitImpl::get_deferred_payout(self, player)
+        }
+
+        /// get balance of player, deposits and unclaimed winnings and refunds
+        #[ink(message)]
+        fn get_claimable(&self, player: AccountId) -> Balance {
+            BetA0CoreTraitImpl::get_claimable(self, player)
+        }
+
+        /// get balance of all players
+        #[ink(message)]
+        fn get_total_claimable(&self) -> Balance {
+            BetA0CoreTraitImpl::get_total_claimable(self)
+        }
+
+        /// is auto push enabled
+        #[ink(message)]
+        fn is_auto_push(&self) -> bool {
+            BetA0CoreTraitImpl::is_auto_push(self)
+        }
+
+        /// is circuit breaker tripped
+        #[ink(message)]
+        fn is_circuit_breaker_tripped(&self) -> bool {
+            BetA0CoreTraitImpl::is_circuit_breaker_tripped(self)
+        }
+
+        /// get circuit breaker window in blocks
+        #[ink(message)]
+        fn get_circuit_breaker_window(&self) -> BlockNumber {
+            BetA0CoreTraitImpl::get_circuit_breaker_window(self)
+        }
+
+        /// get circuit breaker loss limit in bps of bankroll
+        #[ink(message)]
+        fn get_circuit_breaker_max_loss_ratio(&self) -> u32 {
+            BetA0CoreTraitImpl::get_circuit_breaker_max_loss_ratio(self)
+        }
+
+        /// get net house loss over the last circuit breaker window blocks
+        #[ink(message)]
+        fn get_window_house_loss(&self) -> Balance {
+            BetA0CoreTraitImpl::get_window_house_loss(self)
+        }
+
+        /// get bet rate limits
+        #[ink(message)]
+        fn get_rate_limits(&self) -> RateLimits {
+            BetA0CoreTraitImpl::get_rate_limits(self)
+        }
+
+        /// get bet rate counters of player
+        #[ink(message)]
+        fn get_player_bet_rate(&self, player: AccountId) -> PlayerBetRate {
+            BetA0CoreTraitImpl::get_player_bet_rate(self, player)
+        }
+
+        /// get number of bets placed in the current block
+        #[ink(message)]
+        fn get_block_bets(&self) -> u32 {
+            BetA0CoreTraitImpl::get_block_bets(self)
+        }
+
+        /// get tie policy
+        #[ink(message)]
+        fn get_tie_policy(&self) -> TiePolicy {
+            BetA0CoreTraitImpl::get_tie_policy(self)
+        }
+
+        /// get return to player of a bet in bps under the current rates and tie policy
+        #[ink(message)]
+        fn get_rtp(&self, is_over: u8, bet_number: u32) -> u32 {
+            BetA0CoreTraitImpl::get_rtp(self, is_over, bet_number)
+        }
+
+        /// get payout of every allowed bet number on one side
+        #[ink(message)]
+        fn get_payout_table(&self, is_over: u8) -> Vec<PayoutEntry> {
+            BetA0CoreTraitImpl::get_payout_table(self, is_over)
+        }
+    }
+
+    impl GuardedOwnableTraitImpl for BetA0CoreContract {
+        /// Every role of the old owner moves to the new owner, who also
+        /// becomes the role admin
+        fn _after_ownership_moved(&mut self, old_owner: Option<AccountId>, new_owner: AccountId) {
+            for role in [
+                DEFAULT_ADMIN_ROLE,
+                CONFIGURATOR,
+                TREASURER,
+                PAUSER,
+                PROMOTER,
+                FINALIZER,
+            ] {
+                if old_owner.is_some() && AccessControl::has_role(self, role, old_owner) {
+                    access_control::Internal::_do_revoke_role(self, role, old_owner);
+                    access_control::Internal::_setup_role(self, role, Some(new_owner));
+                }
+            }
+            if !AccessControl::has_role(self, DEFAULT_ADMIN_ROLE, Some(new_owner)) {
+                access_control::Internal::_setup_role(self, DEFAULT_ADMIN_ROLE, Some(new_owner));
+            }
+        }
+
+        fn _emit_ownership_proposed_event(&self, pending_owner: AccountId) {
+            self.env().emit_event(OwnershipProposedEvent {
+                owner: Ownable::owner(self),
+                pending_owner,
+            });
+        }
+
+        fn _emit_ownership_moved_event(&self, old_owner: Option<AccountId>, new_owner: AccountId) {
+            self.env().emit_event(OwnershipMovedEvent {
+                old_owner,
+                new_owner,
+            });
+        }
+
+        fn _emit_guardian_changed_event(
+            &self,
+            old_guardian: Option<AccountId>,
+            new_guardian: Option<AccountId>,
+        ) {
+            self.env().emit_event(GuardianChangedEvent {
+                old_guardian,
+                new_guardian,
+            });
+        }
+
+        fn _emit_recovery_delay_changed_event(&self, old_delay: Timestamp, new_delay: Timestamp) {
+            self.env().emit_event(RecoveryDelayChangedEvent {
+                old_delay,
+                new_delay,
+            });
+        }
+
+        fn _emit_recovery_started_event(&self, recovery: OwnerRecovery) {
+            self.env().emit_event(RecoveryStartedEvent {
+                guardian: self.env().caller(),
+                new_owner: recovery.new_owner,
+                eta: recovery.eta,
+            });
+        }
+
+        fn _emit_recovery_cancelled_event(&self, recovery: OwnerRecovery) {
+            self.env().emit_event(RecoveryCancelledEvent {
+                caller: self.env().caller(),
+                new_owner: recovery.new_owner,
+            });
+        }
+    }
+
+    impl GuardedOwnableTrait for BetA0CoreContract {
+        // Execute function
+        /// Propose a new owner, it takes effect once accepted, not while a recovery is pending - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn propose_owner(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::propose_owner(self, new_owner)
+        }
+
+        /// Accept ownership, not while a recovery is pending - only pending owner
+        #[ink(message)]
+        fn accept_ownership(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::accept_ownership(self)
+        }
+
+        /// Set or remove the guardian, not while a recovery is pending - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::set_guardian(self, guardian)
+        }
+
+        /// Set delay between starting and completing a recovery, one to thirty
+        /// days - only Owner
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn set_recovery_delay(
+            &mut self,
+            recovery_delay: Timestamp,
+        ) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::set_recovery_delay(self, recovery_delay)
+        }
+
+        /// Pause the contract - only guardian
+        #[ink(message)]
+        fn guardian_pause(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::guardian_pause(self)
+        }
+
+        /// Start moving ownership to new_owner after the recovery delay - only guardian
+        #[ink(message)]
+        fn start_recovery(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::start_recovery(self, new_owner)
+        }
+
+        /// Cancel a started recovery - only guardian
+        #[ink(message)]
+        fn cancel_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::cancel_recovery(self)
+        }
+
+        /// Transfer ownership once the recovery delay has passed - only guardian
+        #[ink(message)]
+        fn complete_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+            GuardedOwnableTraitImpl::complete_recovery(self)
+        }
+
+        // Get Function
+        /// get pending owner
+        #[ink(message)]
+        fn get_pending_owner(&self) -> Option<AccountId> {
+            GuardedOwnableTraitImpl::get_pending_owner(self)
+        }
+
+        /// get guardian
+        #[ink(message)]
+        fn get_guardian(&self) -> Option<AccountId> {
+            GuardedOwnableTraitImpl::get_guardian(self)
+        }
+
+        /// get recovery delay
+        #[ink(message)]
+        fn get_recovery_delay(&self) -> Timestamp {
+            GuardedOwnableTraitImpl::get_recovery_delay(self)
+        }
+
+        /// get started recovery
+        #[ink(message)]
+        fn get_recovery(&self) -> Option<OwnerRecovery> {
+            GuardedOwnableTraitImpl::get_recovery(self)
+        }
+    }
+
+    impl EligibilityTraitImpl for BetA0CoreContract {
+        fn _emit_eligibility_changed_event(
+            &self,
+            account: AccountId,
+            list: EligibilityList,
+            listed: bool,
+        ) {
+            self.env().emit_event(EligibilityChangedEvent {
+                account,
+                caller: self.env().caller(),
+                list,
+                listed,
+            });
+        }
+
+        fn _emit_attestation_submitted_event(&self, attestation: Attestation) {
+            self.env().emit_event(AttestationSubmittedEvent {
+                account: attestation.account,
+                expires_at: attestation.expires_at,
+            });
+        }
+
+        fn _emit_eligibility_setting_changed_event(
+            &self,
+            setting: EligibilitySetting,
+            old_value: bool,
+            new_value: bool,
+        ) {
+            self.env().emit_event(EligibilitySettingChangedEvent {
+                caller: self.env().caller(),
+                setting,
+                old_value,
+                new_value,
+            });
+        }
+
+        fn _emit_attestation_signer_changed_event(
+            &self,
+            old_signer: Option<[u8; 33]>,
+            new_signer: Option<[u8; 33]>,
+        ) {
+            self.env().emit_event(AttestationSignerChangedEvent {
+                caller: self.env().caller(),
+                old_signer,
+                new_signer,
+            });
+        }
+    }
+
+    impl EligibilityTrait for BetA0CoreContract {
+        // Execute function
+        /// Store a signed attestation for its account, one expiring no later than the stored one changes nothing
+        #[ink(message)]
+        fn submit_attestation(
+            &mut self,
+            attestation: Attestation,
+            signature: [u8; 65],
+        ) -> Result<(), CoreError> {
+            EligibilityTraitImpl::submit_attestation(self, attestation, signature)
+        }
+
+        // Set function
+        /// Add or remove account from the deny list - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_denied(&mut self, account: AccountId, denied: bool) -> Result<(), CoreError> {
+            EligibilityTraitImpl::set_denied(self, account, denied)
+        }
+
+        /// Add or remove account from the allow list - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_allowed(&mut self, account: AccountId, allowed: bool) -> Result<(), CoreError> {
+            EligibilityTraitImpl::set_allowed(self, account, allowed)
+        }
+
+        /// Only allow listed accounts can play while enabled - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_allow_list_enabled(&mut self, enabled: bool) -> Result<(), CoreError> {
+            EligibilityTraitImpl::set_allow_list_enabled(self, enabled)
+        }
+
+        /// Require a valid attestation to play - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_attestation_required(&mut self, required: bool) -> Result<(), CoreError> {
+            EligibilityTraitImpl::set_attestation_required(self, required)
+        }
+
+        /// Set compressed ECDSA key signing attestations, revokes the attestations of the previous key - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_attestation_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError> {
+            EligibilityTraitImpl::set_attestation_signer(self, signer)
+        }
+
+        // Get Function
+        /// Check account may play, with the reason when it may not
+        #[ink(message)]
+        fn check_eligibility(&self, account: AccountId) -> Result<(), EligibilityError> {
+            EligibilityTraitImpl::check_eligibility(self, account)
+        }
+
+        /// Is account on the deny list
+        #[ink(message)]
+        fn is_denied(&self, account: AccountId) -> bool {
+            EligibilityTraitImpl::is_denied(self, account)
+        }
+
+        /// Is account on the allow list
+        #[ink(message)]
+        fn is_allowed(&self, account: AccountId) -> bool {
+            EligibilityTraitImpl::is_allowed(self, account)
+        }
+
+        /// Is allow list enabled
+        #[ink(message)]
+        fn is_allow_list_enabled(&self) -> bool {
+            EligibilityTraitImpl::is_allow_list_enabled(self)
+        }
+
+        /// Is attestation required
+        #[ink(message)]
+        fn is_attestation_required(&self) -> bool {
+            EligibilityTraitImpl::is_attestation_required(self)
+        }
+
+        /// get attestation signer
+        #[ink(message)]
+        fn get_attestation_signer(&self) -> Option<[u8; 33]> {
+            EligibilityTraitImpl::get_attestation_signer(self)
+        }
+
+        /// get expiry of the account attestation
+        #[ink(message)]
+        fn get_attestation_expiry(&self, account: AccountId) -> Option<Timestamp> {
+            EligibilityTraitImpl::get_attestation_expiry(self, account)
+        }
+    }
+
+    impl BonusTraitImpl for BetA0CoreContract {
+        fn _emit_bonus_granted_event(
+            &self,
+            player: AccountId,
+            credits: Balance,
+            wagering_required: Balance,
+            expires_at: Timestamp,
+        ) {
+            self.env().emit_event(BonusGrantedEvent {
+                player,
+                credits,
+                wagering_required,
+                expires_at,
+            });
+        }
+
+        fn _emit_bonus_wagered_event(
+            &self,
+            player: AccountId,
+            wagered: Balance,
+            wagering_required: Balance,
+        ) {
+            self.env().emit_event(BonusWageredEvent {
+                player,
+                wagered,
+                wagering_required,
+            });
+        }
+
+        fn _emit_bonus_unlocked_event(&self, player: AccountId, amount: Balance) {
+            self.env().emit_event(BonusUnlockedEvent { player, amount });
+        }
+
+        fn _emit_bonus_forfeited_event(
+            &self,
+            player: AccountId,
+            credits: Balance,
+            locked_winnings: Balance,
+        ) {
+            self.env().emit_event(BonusForfeitedEvent {
+                player,
+                credits,
+                locked_winnings,
+            });
+        }
+    }
+
+    impl BonusTrait for BetA0CoreContract {
+        // Execute function
+        /// Grant bonus credits to player, they must wager wagering_required in total
+        /// before winnings of bonus bets unlock - only PROMOTER
+        #[ink(message)]
+        #[modifiers(only_role(PROMOTER))]
+        fn grant_bonus(
+            &mut self,
+            player: AccountId,
+            credits: Balance,
+            wagering_required: Balance,
+            expires_at: Timestamp,
+        ) -> Result<(), CoreError> {
+            BonusTraitImpl::grant_bonus(self, player, credits, wagering_required, expires_at)
+        }
+
+        /// Remove the bonus of player, credits and locked winnings are forfeited - only PROMOTER
+        #[ink(message)]
+        #[modifiers(only_role(PROMOTER))]
+        fn revoke_bonus(&mut self, player: AccountId) -> Result<(), CoreError> {
+            BonusTraitImpl::revoke_bonus(self, player)
+        }
+
+        /// Forfeit an expired bonus, anyone may call
+        #[ink(message)]
+        fn forfeit_expired_bonus(&mut self, player: AccountId) -> Result<(), CoreError> {
+            BonusTraitImpl::forfeit_expired_bonus(self, player)
+        }
+
+        // Get Function
+        /// get bonus of player, empty once expired
+        #[ink(message)]
+        fn get_bonus(&self, player: AccountId) -> BonusAccount {
+            BonusTraitImpl::get_bonus(self, player)
+        }
+
+        /// get bonus credits of all players
+        #[ink(message)]
+        fn get_total_bonus_credits(&self) -> Balance {
+            BonusTraitImpl::get_total_bonus_credits(self)
+        }
+
+        /// get locked bonus winnings of all players
+        #[ink(message)]
+        fn get_total_bonus_locked(&self) -> Balance {
+            BonusTraitImpl::get_total_bonus_locked(self)
+        }
+    }
+
+    impl PromoTraitImpl for BetA0CoreContract {
+        fn _emit_promo_signer_changed_event(
+            &self,
+            old_signer: Option<[u8; 33]>,
+            new_signer: Option<[u8; 33]>,
+        ) {
+            self.env().emit_event(PromoSignerChangedEvent {
+                caller: self.env().caller(),
+                old_signer,
+                new_signer,
+            });
+        }
+
+        fn _emit_promo_redeemed_event(&self, player: AccountId, code_id: u32, reward: PromoReward) {
+            self.env().emit_event(PromoRedeemedEvent {
+                player,
+                code_id,
+                reward,
+            });
+        }
+    }
+
+    impl PromoTrait for BetA0CoreContract {
+        // Execute function
+        /// Credit the reward of a promo code signed by the promo signer to the
+        /// caller: BET tokens, bonus credits or a multiplier boost on the next bet
+        /// A boost is rejected once the caller's boosts pass MAX_MULTIPLIER_BOOST
+        #[ink(message)]
+        fn redeem_promo(&mut self, code: PromoCode, signature: [u8; 65]) -> Result<(), CoreError> {
+            PromoTraitImpl::redeem_promo(self, code, signature)
+        }
+
+        // Set function
+        /// Set compressed ECDSA key signing promo codes - only CONFIGURATOR
+        #[ink(message)]
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_promo_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError> {
+            PromoTraitImpl::set_promo_signer(self, signer)
+        }
+
+        // Get Function
+        /// get promo signer
+        #[ink(message)]
+        fn get_promo_signer(&self) -> Option<[u8; 33]> {
+            PromoTraitImpl::get_promo_signer(self)
+        }
+
+        /// get redemptions of a promo code by all accounts
+        #[ink(message)]
+        fn get_promo_redemptions(&self, code_id: u32) -> u32 {
+            PromoTraitImpl::get_promo_redemptions(self, code_id)
+        }
+
+        /// get redemptions of a promo code by account
+        #[ink(message)]
+        fn get_account_promo_redemptions(&self, code_id: u32, account: AccountId) -> u32 {
+            PromoTraitImpl::get_account_promo_redemptions(self, code_id, account)
+        }
+
+        /// get multiplier boost waiting for the next bet of player
+        #[ink(message)]
+        fn get_multiplier_boost(&self, player: AccountId) -> u32 {
+            PromoTraitImpl::get_multiplier_boost(self, player)
+        }
+    }
+
+    impl RelayTraitImpl for BetA0CoreContract {
+        fn _emit_nonce_invalidated_event(&self, player: AccountId, nonce: u64) {
+            self.env()
+                .emit_event(NonceInvalidatedEvent { player, nonce });
+        }
+    }
+
+    impl RelayTrait for BetA0CoreContract {
+        // Execute function
+        /// Use up the next nonce of the caller, a signed bet with it can no
+        /// longer be relayed
         #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_token_ratio(&mut self, token_ratio: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_token_ratio(self, token_ratio)
+        fn invalidate_nonce(&mut self) -> Result<(), CoreError> {
+            RelayTraitImpl::invalidate_nonce(self)
         }
 
-        /// Set max bet ratio
+        // Get Function
+        /// get the nonce the next signed bet of player must use
         #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_max_bet_ratio(&mut self, max_bet_ratio: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_max_bet_ratio(self, max_bet_ratio)
+        fn get_nonce(&self, player: AccountId) -> u64 {
+            RelayTraitImpl::get_nonce(self, player)
         }
+    }
 
-        /// Set revenue_ratio
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_revenue_ratio(&mut self, revenue_ratio: u32) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_revenue_ratio(self, revenue_ratio)
+    impl SessionTraitImpl for BetA0CoreContract {
+        fn _emit_session_key_proposed_event(&self, key: AccountId, session_key: SessionKey) {
+            self.env().emit_event(SessionKeyProposedEvent {
+                player: session_key.player,
+                key,
+                expires_at: session_key.expires_at,
+                max_total_stake: session_key.max_total_stake,
+                allow_over: session_key.allow_over,
+                allow_under: session_key.allow_under,
+            });
         }
 
-        /// Set reward_pool
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_reward_pool(&mut self, reward_pool: AccountId) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_reward_pool(self, reward_pool)
+        fn _emit_session_key_registered_event(&self, key: AccountId, session_key: SessionKey) {
+            self.env().emit_event(SessionKeyRegisteredEvent {
+                player: session_key.player,
+                key,
+                expires_at: session_key.expires_at,
+                max_total_stake: session_key.max_total_stake,
+                allow_over: session_key.allow_over,
+                allow_under: session_key.allow_under,
+            });
         }
 
-        /// Set max bet ratio
+        fn _emit_session_key_revoked_event(&self, player: AccountId, key: AccountId) {
+            self.env()
+                .emit_event(SessionKeyRevokedEvent { player, key });
+        }
+    }
+
+    impl SessionTrait for BetA0CoreContract {
+        // Execute function
+        /// Propose key to bet for the caller from the caller balance with
+        /// `play_with_session_key`, until expires_at and for at most
+        /// max_total_stake in total. The key must accept it with
+        /// `accept_session_key`
         #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_general_pool(&mut self, general_pool: AccountId) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_general_pool(self, general_pool)
+        fn register_session_key(
+            &mut self,
+            key: AccountId,
+            expires_at: Timestamp,
+            max_total_stake: Balance,
+            allow_over: bool,
+            allow_under: bool,
+        ) -> Result<(), CoreError> {
+            SessionTraitImpl::register_session_key(
+                self,
+                key,
+                expires_at,
+                max_total_stake,
+                allow_over,
+                allow_under,
+            )
         }
 
-        /// Set bet_pool
+        /// Accept as the key the registration proposed by player
         #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_bet_pool(&mut self, bet_pool: AccountId) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_bet_pool(self, bet_pool)
+        fn accept_session_key(&mut self, player: AccountId) -> Result<(), CoreError> {
+            SessionTraitImpl::accept_session_key(self, player)
         }
 
-        /// Set admin id
+        /// Revoke a session key of the caller, pending or accepted
         #[ink(message)]
-        #[modifiers(only_owner)]
-        fn set_admin_account(&mut self, admin_account: AccountId) -> Result<(), CoreError> {
-            BetA0CoreTraitImpl::set_admin_account(self, admin_account)
+        fn revoke_session_key(&mut self, key: AccountId) -> Result<(), CoreError> {
+            SessionTraitImpl::revoke_session_key(self, key)
         }
 
         // Get Function
-        /// get min number over roll
+        /// get the terms of a session key
         #[ink(message)]
-        fn get_min_number_over_roll(&self) -> u32 {
-            BetA0CoreTraitImpl::get_min_number_over_roll(self)
+        fn get_session_key(&self, key: AccountId) -> Option<SessionKey> {
+            SessionTraitImpl::get_session_key(self, key)
         }
 
-        /// get max number over roll
+        /// get the terms player proposed for a session key
         #[ink(message)]
-        fn get_max_number_over_roll(&self) -> u32 {
-            BetA0CoreTraitImpl::get_max_number_over_roll(self)
+        fn get_pending_session_key(&self, key: AccountId, player: AccountId) -> Option<SessionKey> {
+            SessionTraitImpl::get_pending_session_key(self, key, player)
         }
+    }
 
-        /// get min number under roll
-        #[ink(message)]
-        fn get_min_number_under_roll(&self) -> u32 {
-            BetA0CoreTraitImpl::get_min_number_under_roll(self)
+    impl ResponsibleGamingTraitImpl for BetA0CoreContract {
+        fn _emit_limit_increase_delay_changed_event(
+            &self,
+            old_delay: Timestamp,
+            new_delay: Timestamp,
+        ) {
+            self.env().emit_event(TimestampConfigChangedEvent {
+                caller: self.env().caller(),
+                param: ConfigParam::LimitIncreaseDelay,
+                old_value: old_delay,
+                new_value: new_delay,
+            });
         }
 
-        /// get max number under roll
-        #[ink(message)]
-        fn get_max_number_under_roll(&self) -> u32 {
-            BetA0CoreTraitImpl::get_max_number_under_roll(self)
+        fn _emit_limits_changed_event(&self, player: AccountId, limits: PlayerLimits) {
+            self.env().emit_event(LimitsChangedEvent { player, limits });
         }
 
-        /// Get token ratio
-        #[ink(message)]
-        fn get_token_ratio(&self) -> u32 {
-            BetA0CoreTraitImpl::get_token_ratio(self)
+        fn _emit_limits_increase_queued_event(&self, player: AccountId, pending: PendingLimits) {
+            self.env().emit_event(LimitsIncreaseQueuedEvent {
+                player,
+                limits: pending.limits,
+                effective_at: pending.effective_at,
+            });
         }
 
-        /// get revenue ratio
+        fn _emit_self_excluded_event(&self, player: AccountId, until: Timestamp) {
+            self.env().emit_event(SelfExcludedEvent { player, until });
+        }
+    }
+
+    impl ResponsibleGamingTrait for BetA0CoreContract {
+        // Execute function
+        /// Set caller limits, lower limits apply now and raised ones after the increase delay
         #[ink(message)]
-        fn get_revenue_ratio(&self) -> u32 {
-            BetA0CoreTraitImpl::get_token_ratio(self)
+        fn set_limits(&mut self, limits: PlayerLimits) -> Result<(), CoreError> {
+            ResponsibleGamingTraitImpl::set_limits(self, limits)
         }
 
-        /// get reward pool
+        /// Exclude caller from play for duration, an exclusion can never be shortened
         #[ink(message)]
-        fn get_reward_pool(&self) -> AccountId {
-            BetA0CoreTraitImpl::get_reward_pool(self)
+        fn self_exclude(&mut self, duration: Timestamp) -> Result<(), CoreError> {
+            ResponsibleGamingTraitImpl::self_exclude(self, duration)
         }
 
-        /// get general pool
+        // Set function
+        /// Set delay before raised limits apply, at least one day - only CONFIGURATOR
         #[ink(message)]
-        fn get_general_pool(&self) -> AccountId {
-            BetA0CoreTraitImpl::get_general_pool(self)
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn set_limit_increase_delay(
+            &mut self,
+            limit_increase_delay: Timestamp,
+        ) -> Result<(), CoreError> {
+            ResponsibleGamingTraitImpl::set_limit_increase_delay(self, limit_increase_delay)
         }
 
-        /// get bet pool
+        // Get Function
+        /// get limits in force for player
         #[ink(message)]
-        fn get_bet_pool(&self) -> AccountId {
-            BetA0CoreTraitImpl::get_bet_pool(self)
+        fn get_limits(&self, player: AccountId) -> PlayerLimits {
+            ResponsibleGamingTraitImpl::get_limits(self, player)
         }
 
-        /// Get psp22 address
+        /// get raised limits waiting for the increase delay
         #[ink(message)]
-        fn bet_token_address(&self) -> AccountId {
-            BetA0CoreTraitImpl::bet_token_address(self)
+        fn get_pending_limits(&self, player: AccountId) -> Option<PendingLimits> {
+            ResponsibleGamingTraitImpl::get_pending_limits(self, player)
         }
 
-        /// Get Over Rates
+        /// get player activity in the current periods
         #[ink(message)]
-        fn get_over_rates(&self) -> Vec<u32> {
-            BetA0CoreTraitImpl::get_over_rates(self)
+        fn get_activity(&self, player: AccountId) -> PlayerActivity {
+            ResponsibleGamingTraitImpl::get_activity(self, player)
         }
 
-        /// Get Under Rates
+        /// get end of player self exclusion
         #[ink(message)]
-        fn get_under_rates(&self) -> Vec<u32> {
-            BetA0CoreTraitImpl::get_under_rates(self)
+        fn get_self_excluded_until(&self, player: AccountId) -> Option<Timestamp> {
+            ResponsibleGamingTraitImpl::get_self_excluded_until(self, player)
         }
 
-        /// Get Max Bet
+        /// get limit increase delay
         #[ink(message)]
-        fn get_max_bet_ratio(&self) -> u32 {
-            BetA0CoreTraitImpl::get_max_bet_ratio(self)
+        fn get_limit_increase_delay(&self) -> Timestamp {
+            ResponsibleGamingTraitImpl::get_limit_increase_delay(self)
+        }
+    }
+
+    impl TimelockTraitImpl for BetA0CoreContract {
+        fn _emit_change_queued_event(&self, change: PendingChange) {
+            self.env().emit_event(ChangeQueuedEvent {
+                id: change.id,
+                caller: self.env().caller(),
+                action: change.action,
+                eta: change.eta,
+            });
         }
 
+        fn _emit_change_executed_event(&self, change: PendingChange) {
+            self.env().emit_event(ChangeExecutedEvent {
+                id: change.id,
+                caller: self.env().caller(),
+                action: change.action,
+            });
+        }
+
+        fn _emit_change_cancelled_event(&self, change: PendingChange) {
+            self.env().emit_event(ChangeCancelledEvent {
+                id: change.id,
+                caller: self.env().caller(),
+                action: change.action,
+            });
+        }
+    }
+
+    impl TimelockTrait for BetA0CoreContract {
+        // Execute function
+        /// Queue a parameter change, eta must be at least the delay from now - only CONFIGURATOR
         #[ink(message)]
-        fn get_max_bet(&self) -> u128 {
-            BetA0CoreTraitImpl::get_max_bet(self)
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn queue_change(
+            &mut self,
+            action: TimelockAction,
+            eta: Timestamp,
+        ) -> Result<u64, CoreError> {
+            TimelockTraitImpl::queue_change(self, action, eta)
         }
 
-        /// get contract token balance
+        /// Apply a queued change once its eta has passed - only CONFIGURATOR
         #[ink(message)]
-        fn get_token_balance(&self) -> Balance {
-            BetA0CoreTraitImpl::get_token_balance(self)
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn execute_change(&mut self, id: u64) -> Result<(), CoreError> {
+            TimelockTraitImpl::execute_change(self, id)
         }
 
-        /// get token balance pool
+        /// Drop a queued change - only CONFIGURATOR
         #[ink(message)]
-        fn get_token_balance_pool(&self, pool: AccountId) -> Balance {
-            BetA0CoreTraitImpl::get_token_balance_pool(self, pool)
+        #[modifiers(only_role(CONFIGURATOR))]
+        fn cancel_change(&mut self, id: u64) -> Result<(), CoreError> {
+            TimelockTraitImpl::cancel_change(self, id)
         }
 
-        /// Is bet exist
+        // Get Function
+        /// get timelock delay
         #[ink(message)]
-        fn is_bet_available(&self, player: AccountId) -> bool {
-            BetA0CoreTraitImpl::is_bet_available(self, player)
+        fn get_timelock_delay(&self) -> Timestamp {
+            TimelockTraitImpl::get_timelock_delay(self)
         }
 
-        /// get admin id
+        /// get queued change
         #[ink(message)]
-        fn get_admin_account(&self) -> AccountId {
-            BetA0CoreTraitImpl::get_admin_account(self)
+        fn get_pending_change(&self, id: u64) -> Option<PendingChange> {
+            TimelockTraitImpl::get_pending_change(self, id)
         }
 
-        /// get bet
+        /// get all queued changes
         #[ink(message)]
-        fn get_bet(&self, player: AccountId) -> Option<BetInformation> {
-            BetA0CoreTraitImpl::get_bet(self, player)
+        fn get_pending_changes(&self) -> Vec<PendingChange> {
+            TimelockTraitImpl::get_pending_changes(self)
         }
     }
 
@@ -339,8 +2257,10 @@ pub mod beta0_core {
             admin_account: AccountId,
         ) -> Self {
             let mut instance = Self::default();
+            instance.manager.storage_version.set(&STORAGE_VERSION);
             let caller = <Self as DefaultEnv>::env().caller();
             ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance._init_defaults(caller).ok().unwrap();
             instance
                 .initialize(
                     max_bet_ratio,
@@ -362,7 +2282,8 @@ pub mod beta0_core {
         }
 
         // EXECUTE FUNCTIONS
-        /// Function init
+        /// Function init, afterwards config changes wait for the default
+        /// timelock delay
         #[ink(message)]
         #[modifiers(only_owner)]
         pub fn initialize(
@@ -409,7 +2330,7 @@ pub mod beta0_core {
             ]
             .to_vec();
             self.manager.max_bet_ratio = max_bet_ratio;
-            assert!((1..=1000).contains(&revenue_ratio));
+            assert!((1..=100).contains(&revenue_ratio));
             self.manager.reward_pool = reward_pool;
             self.manager.general_pool = general_pool;
             self.manager.bet_pool = bet_pool;
@@ -421,216 +2342,1014 @@ pub mod beta0_core {
             self.manager.min_under_number = min_under_number;
             self.manager.max_under_number = max_under_number;
             self.manager.admin_account = admin_account;
+            access_control::Internal::_setup_role(self, FINALIZER, Some(admin_account));
+            self._set_revenue_split(self._revenue_ratio_split(revenue_ratio))?;
+            self._validate_config()?;
+            TimelockTraitImpl::_set_timelock_delay(self, DEFAULT_TIMELOCK_DELAY)?;
             Ok(())
         }
 
-        /// Play
+        /// Play, the bet is rejected if the live rate is below min_multiplier.
+        /// Fails with CoreError, PSP22Error before contract version 2.0.0
         #[ink(message)]
         #[ink(payable)]
-        pub fn play(&mut self, bet_number: u32, is_over: u8) -> Result<(), PSP22Error> {
-            // state contract
-            if pausable::Internal::_paused(self) {
-                return Err(PSP22Error::Custom(String::from("P::Contract is paused")));
-            }
-
+        pub fn play(
+            &mut self,
+            bet_number: u32,
+            is_over: u8,
+            min_multiplier: u32,
+        ) -> Result<(), CoreError> {
             let player = self.env().caller();
             let bet_amount = self.env().transferred_value();
-            let max_bet = (self.env().balance())
-                .checked_div(self.manager.max_bet_ratio as u128)
-                .unwrap();
+            self._place_bet(
+                player,
+                bet_number,
+                is_over,
+                min_multiplier,
+                bet_amount,
+                BetFunding::Wallet,
+            )
+        }
+
+        /// Play with amount taken from the caller balance instead of a transfer,
+        /// winnings are credited back to the balance even with auto push on
+        #[ink(message)]
+        pub fn play_from_balance(
+            &mut self,
+            bet_number: u32,
+            is_over: u8,
+            min_multiplier: u32,
+            amount: Balance,
+        ) -> Result<(), CoreError> {
+            let player = self.env().caller();
+            self._debit_balance(player, amount)?;
+            self._place_bet(
+                player,
+                bet_number,
+                is_over,
+                min_multiplier,
+                amount,
+                BetFunding::Balance,
+            )
+        }
 
-            assert!((1..=max_bet).contains(&bet_amount));
-
-            if is_over == 1 {
-                assert!(
-                    (self.manager.min_over_number..=self.manager.max_over_number)
-                        .contains(&bet_number)
-                );
-            } else if is_over == 0 {
-                assert!(
-                    (self.manager.min_under_number..=self.manager.max_under_number)
-                        .contains(&bet_number)
-                );
+        /// Play a bet signed by the player, submitted by a relayer paying the
+        /// transaction fee. The relayer is credited relayer_fee out of amount.
+        /// Any caller holding the intent may submit it and takes the fee.
+        ///
+        /// Scope: the stake only comes from the internal balance, a PSP22
+        /// allowance cannot fund it because stakes and payouts are native.
+        /// Intents are signed with an ECDSA key and player is the blake2 hash
+        /// of its public key. ink 4 has no sr25519 verification, so sr25519
+        /// accounts cannot sign intents and play directly instead.
+        #[ink(message)]
+        pub fn play_signed(
+            &mut self,
+            intent: BetIntent,
+            signature: [u8; 65],
+        ) -> Result<(), CoreError> {
+            let relayer = self.env().caller();
+            self._use_bet_intent(&intent, &signature)?;
+
+            let bet_amount = intent
+                .amount
+                .checked_sub(intent.relayer_fee)
+                .ok_or(CoreError::Custom(String::from("O::Invalid Input")))?;
+            self._debit_balance(intent.player, intent.amount)?;
+            if intent.relayer_fee > 0 {
+                let balance = self._credit_balance(relayer, intent.relayer_fee);
+                self._emit_credited_event(relayer, intent.relayer_fee, balance);
             }
 
-            let bet_info = self.manager.bets.get(&player);
+            self._place_bet(
+                intent.player,
+                intent.bet_number,
+                intent.is_over,
+                intent.min_multiplier,
+                bet_amount,
+                BetFunding::Balance,
+            )?;
+
+            self.env().emit_event(BetRelayedEvent {
+                player: intent.player,
+                relayer,
+                nonce: intent.nonce,
+                relayer_fee: intent.relayer_fee,
+            });
+            Ok(())
+        }
+
+        /// Play as a session key, amount is taken from the balance of the
+        /// player that registered the key and winnings go to that balance
+        #[ink(message)]
+        pub fn play_with_session_key(
+            &mut self,
+            bet_number: u32,
+            is_over: u8,
+            min_multiplier: u32,
+            amount: Balance,
+        ) -> Result<(), CoreError> {
+            let key = self.env().caller();
+            let player = self._spend_session_stake(key, is_over, amount)?;
+            self._debit_balance(player, amount)?;
+            self._place_bet(
+                player,
+                bet_number,
+                is_over,
+                min_multiplier,
+                amount,
+                BetFunding::Balance,
+            )
+        }
+
+        /// Play with amount taken from the caller bonus credits
+        #[ink(message)]
+        pub fn play_with_bonus(
+            &mut self,
+            bet_number: u32,
+            is_over: u8,
+            min_multiplier: u32,
+            amount: Balance,
+        ) -> Result<(), CoreError> {
+            let player = self.env().caller();
+            self._spend_bonus(player, amount)?;
+            self._place_bet(
+                player,
+                bet_number,
+                is_over,
+                min_multiplier,
+                amount,
+                BetFunding::Bonus,
+            )
+        }
+
+        fn _place_bet(
+            &mut self,
+            player: AccountId,
+            bet_number: u32,
+            is_over: u8,
+            min_multiplier: u32,
+            bet_amount: Balance,
+            funding: BetFunding,
+        ) -> Result<(), CoreError> {
+            let is_bonus = funding == BetFunding::Bonus;
+            self._check_bet(player, is_over, bet_number, bet_amount)?;
+
+            // a redeemed promo boost applies to the next bet only, the
+            // payout cap was checked with it in _check_bet
+            let multiplier = self._get_multiplier(player, is_over, bet_number)?;
+            self._take_multiplier_boost(player);
+            if multiplier < min_multiplier {
+                return Err(CoreError::Custom(String::from("O::Rate Below Minimum")));
+            }
 
-            if let Some(_unwrapped_bet_info) = bet_info {
-                return Err(PSP22Error::Custom(String::from("O::Bet Not Finalized")));
+            self._record_bet_rate(player);
+            self._progress_bonus(player, bet_amount);
+            // every stake counts toward the player limits, bonus ones too
+            self._record_wager(player, bet_amount);
+            if !is_bonus {
+                self._lock_escrow(bet_amount);
             }
 
+            let bet_id = self.manager.bet_counter.get_or_default();
+            self.manager
+                .bet_counter
+                .set(&bet_id.checked_add(1).unwrap());
+            let block_number = self.env().block_number();
+
             let new_bet = BetInformation {
                 is_over,
                 bet_number,
                 bet_amount,
+                bet_id,
+                placed_at: block_number,
+                multiplier,
+                revenue_split_id: self.manager.revenue_split_id.get_or_default(),
+                token_ratio: self.manager.token_ratio,
+                tie_refund: self._get_tie_refund(),
+                is_bonus,
+                from_balance: funding == BetFunding::Balance,
             };
 
             //Update listed token
-            self.manager.bets.insert(&player, &new_bet);
+            self._store_bet(player, &new_bet);
 
             self.env().emit_event(PlayEvent {
-                player: Some(player),
+                player,
+                bet_id,
+                version: GAME_EVENT_VERSION,
+                block_number,
                 is_over,
                 bet_number,
                 bet_amount,
+                is_bonus,
+                multiplier,
             });
 
             Ok(())
         }
 
-        /// Finalize Bet
+        /// Finalize Bet - only FINALIZER
+        /// `proof` lets anyone verify `random_number` against the randomness
+        /// source off chain, it is not checked here and only emitted with
+        /// the settlement event
         #[ink(message)]
-        pub fn finalize(&mut self, player: AccountId, random_number: u32) -> Result<(), CoreError> {
+        #[modifiers(only_role(FINALIZER))]
+        pub fn finalize(
+            &mut self,
+            player: AccountId,
+            random_number: u32,
+            proof: Vec<u8>,
+        ) -> Result<(), CoreError> {
             // state contract
-            if pausable::Internal::_paused(self) {
-                return Err(CoreError::Custom(String::from("P::Contract is paused")));
-            }
+            self._check_operation(PausableOperation::Settlement)?;
 
-            let caller = self.env().caller();
+            self._check_migrated()?;
+            self._check_not_shutdown()?;
 
-            if caller != self.manager.admin_account {
-                return Err(CoreError::Custom(String::from("O::Caller is not admin")));
-            }
+            let caller = self.env().caller();
 
-            let bet_info = self.manager.bets.get(&player);
+            let bet_info = self._take_bet(player);
 
             if let Some(unwrapped_bet_info) = bet_info {
-                let bet_number = unwrapped_bet_info.bet_number.clone();
-                let bet_amount = unwrapped_bet_info.bet_amount.clone();
-                let is_over = unwrapped_bet_info.is_over.clone();
-
-                self.manager.bets.remove(&player);
-
-                if is_over == 1 {
-                    assert!(
-                        (self.manager.min_over_number..=self.manager.max_over_number)
-                            .contains(&bet_number)
-                    );
-                    if random_number > bet_number {
-                        // WIN
-                        // How much to send to winner
-                        let win_amount = (self.manager.over_rates[bet_number as usize] as Balance)
-                            .checked_mul(bet_amount)
-                            .unwrap()
-                            .checked_div(10000)
-                            .unwrap();
-                        if win_amount.checked_sub(bet_amount) > Some(self.env().balance()) {
-                            return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
-                        }
+                let BetInformation {
+                    is_over,
+                    bet_number,
+                    bet_amount,
+                    bet_id,
+                    placed_at,
+                    multiplier,
+                    revenue_split_id,
+                    token_ratio,
+                    tie_refund,
+                    is_bonus,
+                    from_balance,
+                } = unwrapped_bet_info;
 
-                        assert!(self.env().transfer(player, win_amount).is_ok());
-
-                        // event
-                        self.env().emit_event(WinEvent {
-                            player: Some(player),
-                            is_over,
-                            random_number,
-                            bet_number,
-                            bet_amount,
-                            win_amount,
-                        });
-                    } else {
-                        // LOSE
-                        // send to pool
-                        let lose_amount = bet_amount
-                            .checked_mul(self.manager.revenue_ratio as u128)
-                            .unwrap()
-                            .checked_div(100)
-                            .unwrap();
-
-                        assert!(self
-                            .env()
-                            .transfer(self.manager.reward_pool, lose_amount)
-                            .is_ok());
-
-                        assert!(self
-                            .env()
-                            .transfer(
-                                self.manager.general_pool,
-                                bet_amount.checked_sub(lose_amount).unwrap()
-                            )
-                            .is_ok());
-
-                        // event
-                        self.env().emit_event(LoseEvent {
-                            player: Some(player),
-                            is_over,
-                            random_number,
-                            bet_number,
-                            bet_amount,
-                        });
-                    }
+                if !is_bonus {
+                    self._release_escrow(bet_amount);
+                }
+
+                // bet_number was checked against the allowed range in play
+                let is_win = if is_over == 1 {
+                    random_number > bet_number
                 } else if is_over == 0 {
-                    assert!(
-                        (self.manager.min_under_number..=self.manager.max_under_number)
-                            .contains(&bet_number)
-                    );
-                    if random_number < bet_number {
-                        // WIN
-                        // How much to send to winner
-                        let win_amount = (self.manager.under_rates[bet_number as usize] as Balance)
-                            .checked_mul(bet_amount)
-                            .unwrap()
-                            .checked_div(10000)
-                            .unwrap();
-                        if win_amount.checked_sub(bet_amount) > Some(self.env().balance()) {
-                            return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
+                    random_number < bet_number
+                } else {
+                    return Err(CoreError::Custom(String::from("O::Invalid Input")));
+                };
+
+                if is_win {
+                    // WIN
+                    // How much to send to winner
+                    let win_amount = (multiplier as Balance)
+                        .checked_mul(bet_amount)
+                        .unwrap()
+                        .checked_div(10000)
+                        .unwrap();
+                    let win_amount = self._cap_payout(win_amount, bet_amount);
+                    let bankroll = self
+                        .env()
+                        .balance()
+                        .saturating_sub(self._get_owed_to_players());
+                    // The released stake is already in the bankroll, so the whole
+                    // win must fit without touching what is owed to players
+                    if win_amount > bankroll {
+                        return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
+                    }
+                    // A bonus stake was never paid in, the house pays the whole win
+                    let stake = if is_bonus { 0 } else { bet_amount };
+
+                    let mut deferred = false;
+                    let mut reward_tokens = 0;
+                    if is_bonus {
+                        let unlocked = self._lock_bonus_winnings(player, win_amount);
+                        self._credit_unlocked_bonus(player, unlocked);
+                    } else {
+                        // Over the block payout cap the win is kept for release_deferred_payout
+                        deferred = !self._reserve_block_payout(win_amount);
+                        if deferred {
+                            self._defer_payout(player, win_amount, from_balance);
+                        } else {
+                            self._pay_player(player, win_amount, from_balance);
                         }
-
-                        assert!(self.env().transfer(player, win_amount).is_ok());
-
-                        // event
-                        self.env().emit_event(WinEvent {
-                            player: Some(player),
-                            is_over,
-                            random_number,
-                            bet_number,
-                            bet_amount,
-                            win_amount,
-                        });
+                        reward_tokens = self._settle_reward(player, bet_amount, token_ratio);
+                    }
+                    self._record_return(player, win_amount);
+                    self._record_house_result(win_amount.saturating_sub(stake), 0);
+
+                    // event
+                    self.env().emit_event(WinEvent {
+                        player,
+                        bet_id,
+                        version: GAME_EVENT_VERSION,
+                        block_number: self.env().block_number(),
+                        placed_at,
+                        finalizer: caller,
+                        is_over,
+                        random_number,
+                        proof,
+                        bet_number,
+                        bet_amount,
+                        is_bonus,
+                        multiplier,
+                        win_amount,
+                        deferred,
+                        reward_tokens,
+                    });
+                } else if random_number == bet_number && tie_refund > 0 {
+                    // PUSH
+                    // refund under the tie policy, the rest goes to pool
+                    let refund_amount = bet_amount
+                        .checked_mul(tie_refund as u128)
+                        .unwrap()
+                        .checked_div(10000)
+                        .unwrap();
+                    let house_amount = bet_amount.checked_sub(refund_amount).unwrap();
+
+                    let mut revenue = Vec::new();
+                    let mut reward_tokens = 0;
+                    self._record_return(player, refund_amount);
+                    if is_bonus {
+                        self._return_bonus(player, refund_amount);
                     } else {
-                        // LOSE
-                        // send to pool
-                        let lose_amount = bet_amount
-                            .checked_mul(self.manager.revenue_ratio as u128)
-                            .unwrap()
-                            .checked_div(100)
-                            .unwrap();
-
-                        assert!(self
-                            .env()
-                            .transfer(self.manager.reward_pool, lose_amount)
-                            .is_ok());
-
-                        assert!(self
-                            .env()
-                            .transfer(
-                                self.manager.general_pool,
-                                bet_amount.checked_sub(lose_amount).unwrap()
-                            )
-                            .is_ok());
-
-                        // event
-                        self.env().emit_event(LoseEvent {
-                            player: Some(player),
-                            is_over,
-                            random_number,
-                            bet_number,
-                            bet_amount,
-                        });
+                        self._pay_player(player, refund_amount, from_balance);
+                        revenue = self._distribute_revenue(house_amount, revenue_split_id);
+                        self._record_house_result(0, self._get_retained_revenue(&revenue));
+                        reward_tokens = self._settle_reward(player, bet_amount, token_ratio);
                     }
+
+                    // event
+                    self.env().emit_event(PushEvent {
+                        player,
+                        bet_id,
+                        version: GAME_EVENT_VERSION,
+                        block_number: self.env().block_number(),
+                        placed_at,
+                        finalizer: caller,
+                        is_over,
+                        random_number,
+                        proof,
+                        bet_number,
+                        bet_amount,
+                        is_bonus,
+                        refund_amount,
+                        revenue_split_id,
+                        revenue,
+                        reward_tokens,
+                    });
                 } else {
-                    return Err(CoreError::Custom(String::from("O::Invalid Input")));
-                }
+                    // LOSE
+                    // send to pool, a bonus stake has nothing to send
+                    let mut revenue = Vec::new();
+                    let mut reward_tokens = 0;
+                    if !is_bonus {
+                        revenue = self._distribute_revenue(bet_amount, revenue_split_id);
+                        self._record_house_result(0, self._get_retained_revenue(&revenue));
+                        reward_tokens = self._settle_reward(player, bet_amount, token_ratio);
+                    }
 
-                // assert!(self.reward_token_to_player(player, bet_amount).is_ok());
-                assert!(BetA0CoreTrait::reward_token_to_player(self, player, bet_amount).is_ok());
+                    // event
+                    self.env().emit_event(LoseEvent {
+                        player,
+                        bet_id,
+                        version: GAME_EVENT_VERSION,
+                        block_number: self.env().block_number(),
+                        placed_at,
+                        finalizer: caller,
+                        is_over,
+                        random_number,
+                        proof,
+                        bet_number,
+                        bet_amount,
+                        is_bonus,
+                        multiplier,
+                        revenue_split_id,
+                        revenue,
+                        reward_tokens,
+                    });
+                }
 
-                // PSP22Ref::mint(&self.manager.psp22,player,bet_amount/(self.manager.token_ratio as u256));
                 Ok(())
             } else {
                 return Err(CoreError::Custom(String::from("O::Bet Not Exist")));
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use bet_a0::impls::signature;
+        use ink::env::{test, DefaultEnvironment};
+        use secp256k1::{Message, PublicKey, SecretKey, SECP256K1};
+
+        fn new_contract() -> BetA0CoreContract {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            BetA0CoreContract::new(
+                100,
+                10,
+                accounts.bob,
+                accounts.charlie,
+                accounts.django,
+                accounts.eve,
+                1,
+                4,
+                98,
+                1,
+                95,
+                accounts.alice,
+            )
+        }
+
+        fn player_of(key: &SecretKey) -> AccountId {
+            signature::account_id_of(&PublicKey::from_secret_key(SECP256K1, key).serialize())
+        }
+
+        fn bet_intent(player: AccountId) -> BetIntent {
+            BetIntent {
+                player,
+                contract: test::callee::<DefaultEnvironment>(),
+                bet_number: 50,
+                is_over: 1,
+                min_multiplier: 0,
+                amount: 100,
+                relayer_fee: 10,
+                nonce: 0,
+                deadline: 1000,
+            }
+        }
+
+        fn sign<T: signature::SignedPayload>(key: &SecretKey, payload: &T) -> [u8; 65] {
+            let message = Message::from_slice(&signature::signing_hash(payload)).unwrap();
+            let (recovery_id, compact) = SECP256K1
+                .sign_ecdsa_recoverable(&message, key)
+                .serialize_compact();
+            let mut signed = [0u8; 65];
+            signed[..64].copy_from_slice(&compact);
+            signed[64] = recovery_id.to_i32() as u8;
+            signed
+        }
+
+        fn custom_error(message: &str) -> Result<(), CoreError> {
+            Err(CoreError::Custom(String::from(message)))
+        }
+
+        #[ink::test]
+        fn signed_bet_nonce_is_used_once() {
+            let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+            let mut contract = new_contract();
+            let intent = bet_intent(player_of(&key));
+            let signed = sign(&key, &intent);
+
+            assert!(contract._use_bet_intent(&intent, &signed).is_ok());
+            assert_eq!(RelayTrait::get_nonce(&contract, intent.player), 1);
+            assert_eq!(
+                contract.play_signed(intent, signed),
+                custom_error("O::Invalid Nonce")
+            );
+        }
+
+        #[ink::test]
+        fn signed_bet_for_another_contract_is_rejected() {
+            let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+            let mut contract = new_contract();
+            let mut intent = bet_intent(player_of(&key));
+            intent.contract = test::default_accounts::<DefaultEnvironment>().bob;
+
+            assert_eq!(
+                contract.play_signed(intent, sign(&key, &intent)),
+                custom_error("O::Invalid Bet Signature")
+            );
+        }
+
+        #[ink::test]
+        fn expired_signed_bet_is_rejected() {
+            let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+            let mut contract = new_contract();
+            let mut intent = bet_intent(player_of(&key));
+            intent.deadline = 0;
+
+            assert_eq!(
+                contract.play_signed(intent, sign(&key, &intent)),
+                custom_error("O::Bet Expired")
+            );
+        }
+
+        #[ink::test]
+        fn signed_bet_of_another_key_is_rejected() {
+            let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+            let other_key = SecretKey::from_slice(&[2u8; 32]).unwrap();
+            let mut contract = new_contract();
+            let intent = bet_intent(player_of(&key));
+
+            assert_eq!(
+                contract.play_signed(intent, sign(&other_key, &intent)),
+                custom_error("O::Invalid Bet Signature")
+            );
+            assert_eq!(RelayTrait::get_nonce(&contract, intent.player), 0);
+        }
+
+        #[ink::test]
+        fn signed_bet_with_fee_over_amount_is_rejected() {
+            let key = SecretKey::from_slice(&[1u8; 32]).unwrap();
+            let mut contract = new_contract();
+            let mut intent = bet_intent(player_of(&key));
+            intent.relayer_fee = intent.amount + 1;
+
+            assert_eq!(
+                contract.play_signed(intent, sign(&key, &intent)),
+                custom_error("O::Invalid Input")
+            );
+        }
+
+        #[ink::test]
+        fn session_key_is_taken_only_once_accepted_and_freed_on_expiry() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let key = accounts.django;
+            let mut contract = new_contract();
+
+            test::set_caller::<DefaultEnvironment>(accounts.frank);
+            assert!(
+                SessionTrait::register_session_key(&mut contract, key, 100, 50, true, true).is_ok()
+            );
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert!(
+                SessionTrait::register_session_key(&mut contract, key, 100, 50, true, true).is_ok()
+            );
+            assert_eq!(SessionTrait::get_session_key(&contract, key), None);
+
+            // the key picks the player it bets for
+            test::set_caller::<DefaultEnvironment>(key);
+            assert!(SessionTrait::accept_session_key(&mut contract, accounts.frank).is_ok());
+            assert_eq!(
+                SessionTrait::accept_session_key(&mut contract, accounts.eve),
+                custom_error("O::Session Key In Use")
+            );
+
+            // an expired key can be registered again
+            test::set_block_timestamp::<DefaultEnvironment>(100);
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert!(
+                SessionTrait::register_session_key(&mut contract, key, 200, 50, true, true).is_ok()
+            );
+            test::set_caller::<DefaultEnvironment>(key);
+            assert!(SessionTrait::accept_session_key(&mut contract, accounts.eve).is_ok());
+            assert_eq!(
+                SessionTrait::get_session_key(&contract, key).map(|session_key| session_key.player),
+                Some(accounts.eve)
+            );
+        }
+
+        #[ink::test]
+        fn owner_cannot_hand_over_during_a_recovery() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let mut contract = new_contract();
+            assert!(GuardedOwnableTrait::set_guardian(&mut contract, Some(accounts.bob)).is_ok());
+            assert_eq!(
+                GuardedOwnableTrait::set_recovery_delay(&mut contract, u64::MAX),
+                Err(GuardedOwnableError::RecoveryDelayTooLong)
+            );
+            assert!(GuardedOwnableTrait::propose_owner(&mut contract, accounts.eve).is_ok());
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(GuardedOwnableTrait::start_recovery(&mut contract, accounts.charlie).is_ok());
+
+            // neither a proposal made before the recovery nor a new one goes through
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                GuardedOwnableTrait::accept_ownership(&mut contract),
+                Err(GuardedOwnableError::RecoveryPending)
+            );
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                GuardedOwnableTrait::propose_owner(&mut contract, accounts.eve),
+                Err(GuardedOwnableError::RecoveryPending)
+            );
+            assert!(Ownable::transfer_ownership(&mut contract, Some(accounts.eve)).is_err());
+            assert_eq!(
+                GuardedOwnableTrait::set_guardian(&mut contract, None),
+                Err(GuardedOwnableError::RecoveryPending)
+            );
+            assert_eq!(Ownable::owner(&contract), Some(accounts.alice));
+        }
+
+        #[ink::test]
+        fn deferred_wins_over_the_block_cap_are_released_in_parts() {
+            let player = test::default_accounts::<DefaultEnvironment>().frank;
+            let mut contract = new_contract();
+            contract.manager.max_block_payout.set(&100);
+
+            // two wins deferred in one block add up to more than the cap
+            contract._defer_payout(player, 60, false);
+            contract._defer_payout(player, 60, false);
+            assert_eq!(BetA0CoreTrait::get_deferred_payout(&contract, player), 120);
+
+            assert!(BetA0CoreTrait::release_deferred_payout(&mut contract, player).is_ok());
+            assert_eq!(BetA0CoreTrait::get_deferred_payout(&contract, player), 20);
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 100);
+            assert!(BetA0CoreTrait::release_deferred_payout(&mut contract, player).is_err());
+
+            test::advance_block::<DefaultEnvironment>();
+            assert!(BetA0CoreTrait::release_deferred_payout(&mut contract, player).is_ok());
+            assert_eq!(BetA0CoreTrait::get_deferred_payout(&contract, player), 0);
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 120);
+            assert_eq!(contract.manager.total_deferred_payouts.get_or_default(), 0);
+        }
+
+        #[ink::test]
+        fn wins_of_balance_bets_are_credited_with_auto_push_on() {
+            let player = test::default_accounts::<DefaultEnvironment>().frank;
+            let mut contract = new_contract();
+            contract.manager.auto_push.set(&true);
+
+            contract._pay_player(player, 50, true);
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 50);
+
+            contract._defer_payout(player, 30, true);
+            assert!(BetA0CoreTrait::release_deferred_payout(&mut contract, player).is_ok());
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 80);
+            assert!(!contract.manager.deferred_to_balance.contains(&player));
+        }
+
+        #[ink::test]
+        fn circuit_breaker_losses_roll_out_of_the_window() {
+            let mut contract = new_contract();
+            test::set_account_balance::<DefaultEnvironment>(
+                test::callee::<DefaultEnvironment>(),
+                1_000_000,
+            );
+            contract.manager.breaker_window.set(&10);
+            contract.manager.breaker_max_loss_ratio.set(&10000);
+
+            contract._record_house_result(50, 0);
+            for _ in 0..9 {
+                test::advance_block::<DefaultEnvironment>();
+            }
+            contract._record_house_result(0, 20);
+            assert_eq!(BetA0CoreTrait::get_window_house_loss(&contract), 30);
+
+            // the first loss leaves the window, the later gain stays
+            test::advance_block::<DefaultEnvironment>();
+            assert_eq!(BetA0CoreTrait::get_window_house_loss(&contract), 0);
+            contract._record_house_result(10, 0);
+            assert_eq!(contract.manager.breaker_buckets.get_or_default().len(), 2);
+            assert!(!BetA0CoreTrait::is_circuit_breaker_tripped(&contract));
+        }
+
+        #[ink::test]
+        fn timelocked_changes_wait_for_the_delay() {
+            let mut contract = new_contract();
+            let delay = TimelockTrait::get_timelock_delay(&contract);
+            assert_eq!(delay, DEFAULT_TIMELOCK_DELAY);
+            assert_eq!(
+                BetA0CoreTrait::set_token_ratio(&mut contract, 20),
+                custom_error("O::Timelocked, Use Queue Change")
+            );
+            assert_eq!(
+                TimelockTrait::queue_change(
+                    &mut contract,
+                    TimelockAction::SetTimelockDelay(0),
+                    delay
+                ),
+                Err(CoreError::Custom(String::from("O::Invalid Timelock Delay")))
+            );
+            assert_eq!(
+                TimelockTrait::queue_change(
+                    &mut contract,
+                    TimelockAction::SetTokenRatio(20),
+                    delay - 1
+                ),
+                Err(CoreError::Custom(String::from("O::Eta Before Delay")))
+            );
+
+            let early = TimelockTrait::queue_change(
+                &mut contract,
+                TimelockAction::SetTokenRatio(20),
+                delay,
+            )
+            .unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(delay - 1);
+            assert_eq!(
+                TimelockTrait::execute_change(&mut contract, early),
+                custom_error("O::Timelock Not Expired")
+            );
+
+            let id = TimelockTrait::queue_change(
+                &mut contract,
+                TimelockAction::SetTokenRatio(20),
+                2 * delay,
+            )
+            .unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(2 * delay);
+            assert!(TimelockTrait::execute_change(&mut contract, id).is_ok());
+            assert_eq!(BetA0CoreTrait::get_token_ratio(&contract), 20);
+        }
+
+        #[ink::test]
+        fn legacy_bets_settle_only_after_migration() {
+            let player = test::default_accounts::<DefaultEnvironment>().frank;
+            let mut contract = new_contract();
+            test::set_account_balance::<DefaultEnvironment>(
+                test::callee::<DefaultEnvironment>(),
+                1_000_000,
+            );
+            BetA0CoreTrait::set_operation_paused(&mut contract, PausableOperation::Rewards, true)
+                .unwrap();
+            // storage of the first release with a bet still pending
+            contract.manager.storage_version.set(&0);
+            contract.manager.bets.insert(
+                &player,
+                &LegacyBetInformation {
+                    is_over: 1,
+                    bet_number: 50,
+                    bet_amount: 100,
+                },
+            );
+
+            assert_eq!(
+                contract.finalize(player, 10, Vec::new()),
+                custom_error("O::Migration Pending")
+            );
+            assert!(BetA0CoreTrait::migrate(&mut contract).is_ok());
+            assert_eq!(
+                BetA0CoreTrait::get_storage_version(&contract),
+                STORAGE_VERSION
+            );
+            assert_eq!(
+                BetA0CoreTrait::migrate(&mut contract),
+                custom_error("O::Already Migrated")
+            );
+            assert_eq!(
+                BetA0CoreTrait::get_bet(&contract, player).map(|bet| bet.bet_id),
+                Some(LEGACY_BET_ID)
+            );
+
+            assert!(contract.finalize(player, 10, Vec::new()).is_ok());
+            assert_eq!(BetA0CoreTrait::get_bet(&contract, player), None);
+            assert_eq!(BetA0CoreTrait::get_escrowed_stakes(&contract), 0);
+        }
+
+        #[ink::test]
+        fn emergency_shutdown_stops_bets_and_refunds_stakes() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let mut contract = new_contract();
+            test::set_account_balance::<DefaultEnvironment>(
+                test::callee::<DefaultEnvironment>(),
+                1_000_000,
+            );
+            contract._credit_balance(accounts.frank, 1000);
+
+            test::set_caller::<DefaultEnvironment>(accounts.frank);
+            assert!(contract.play_from_balance(50, 1, 0, 100).is_ok());
+            assert_eq!(
+                BetA0CoreTrait::refund_bet(&mut contract),
+                custom_error("O::Not Shutdown")
+            );
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(BetA0CoreTrait::emergency_shutdown(&mut contract).is_ok());
+            assert_eq!(
+                BetA0CoreTrait::emergency_shutdown(&mut contract),
+                custom_error("O::Already Shutdown")
+            );
+            assert_eq!(
+                contract.finalize(accounts.frank, 10, Vec::new()),
+                custom_error("O::Emergency Shutdown")
+            );
+
+            test::set_caller::<DefaultEnvironment>(accounts.frank);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            assert_eq!(
+                contract.play(50, 1, 0),
+                custom_error("O::Emergency Shutdown")
+            );
+
+            // the stake of the pending bet goes back to the balance once
+            assert!(BetA0CoreTrait::refund_bet(&mut contract).is_ok());
+            assert_eq!(
+                BetA0CoreTrait::get_claimable(&contract, accounts.frank),
+                1000
+            );
+            assert_eq!(BetA0CoreTrait::get_escrowed_stakes(&contract), 0);
+            assert_eq!(
+                BetA0CoreTrait::refund_bet(&mut contract),
+                custom_error("O::Bet Not Exist")
+            );
+        }
+
+        #[ink::test]
+        fn raised_limits_wait_and_exclusion_cannot_be_shortened() {
+            let player = test::default_accounts::<DefaultEnvironment>().frank;
+            let mut contract = new_contract();
+            test::set_caller::<DefaultEnvironment>(player);
+            test::set_block_timestamp::<DefaultEnvironment>(1000);
+
+            let lower = PlayerLimits {
+                daily_wager: Some(100),
+                ..Default::default()
+            };
+            assert!(ResponsibleGamingTrait::set_limits(&mut contract, lower).is_ok());
+            assert_eq!(ResponsibleGamingTrait::get_limits(&contract, player), lower);
+
+            // a raise only applies after the increase delay
+            let raised = PlayerLimits {
+                daily_wager: Some(500),
+                ..Default::default()
+            };
+            assert!(ResponsibleGamingTrait::set_limits(&mut contract, raised).is_ok());
+            assert_eq!(ResponsibleGamingTrait::get_limits(&contract, player), lower);
+            contract._record_wager(player, 60);
+            assert_eq!(
+                contract._check_player_limits(player, 50),
+                custom_error("O::Wager Limit Reached")
+            );
+            test::set_block_timestamp::<DefaultEnvironment>(1000 + DEFAULT_LIMIT_INCREASE_DELAY);
+            assert_eq!(
+                ResponsibleGamingTrait::get_limits(&contract, player),
+                raised
+            );
+            assert_eq!(
+                ResponsibleGamingTrait::get_pending_limits(&contract, player),
+                None
+            );
+
+            // excluding for good saturates and can never be shortened
+            assert!(ResponsibleGamingTrait::self_exclude(&mut contract, u64::MAX).is_ok());
+            assert_eq!(
+                ResponsibleGamingTrait::get_self_excluded_until(&contract, player),
+                Some(u64::MAX)
+            );
+            assert_eq!(
+                ResponsibleGamingTrait::self_exclude(&mut contract, DAY),
+                custom_error("O::Exclusion Cannot Be Shortened")
+            );
+            assert_eq!(
+                contract._check_player_limits(player, 1),
+                custom_error("O::Self Excluded")
+            );
+        }
+
+        #[ink::test]
+        fn attestations_keep_the_later_expiry_and_die_with_their_signer() {
+            let player = test::default_accounts::<DefaultEnvironment>().frank;
+            let mut contract = new_contract();
+            let key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+            let signer = PublicKey::from_secret_key(SECP256K1, &key).serialize();
+            assert!(EligibilityTrait::set_attestation_signer(&mut contract, Some(signer)).is_ok());
+            assert!(EligibilityTrait::set_attestation_required(&mut contract, true).is_ok());
+            assert_eq!(
+                EligibilityTrait::check_eligibility(&contract, player),
+                Err(EligibilityError::AttestationRequired)
+            );
+
+            let attestation = Attestation {
+                account: player,
+                contract: test::callee::<DefaultEnvironment>(),
+                expires_at: 2000,
+            };
+            let signed = sign(&key, &attestation);
+            assert!(
+                EligibilityTrait::submit_attestation(&mut contract, attestation, signed).is_ok()
+            );
+
+            // replaying an older attestation does not shorten the expiry
+            let older = Attestation {
+                expires_at: 1500,
+                ..attestation
+            };
+            assert!(
+                EligibilityTrait::submit_attestation(&mut contract, older, sign(&key, &older))
+                    .is_ok()
+            );
+            assert_eq!(
+                EligibilityTrait::get_attestation_expiry(&contract, player),
+                Some(2000)
+            );
+            assert!(EligibilityTrait::check_eligibility(&contract, player).is_ok());
+
+            // a new signer revokes what the previous one attested
+            let new_key = SecretKey::from_slice(&[8u8; 32]).unwrap();
+            let new_signer = PublicKey::from_secret_key(SECP256K1, &new_key).serialize();
+            assert!(
+                EligibilityTrait::set_attestation_signer(&mut contract, Some(new_signer)).is_ok()
+            );
+            assert_eq!(
+                EligibilityTrait::get_attestation_expiry(&contract, player),
+                None
+            );
+            assert_eq!(
+                EligibilityTrait::check_eligibility(&contract, player),
+                Err(EligibilityError::AttestationRequired)
+            );
+            assert_eq!(
+                EligibilityTrait::submit_attestation(&mut contract, attestation, signed),
+                Err(EligibilityError::InvalidAttestationSignature.into())
+            );
+        }
+
+        #[ink::test]
+        fn bonus_winnings_unlock_once_the_wagering_is_met() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let player = accounts.frank;
+            let mut contract = new_contract();
+            test::set_account_balance::<DefaultEnvironment>(
+                test::callee::<DefaultEnvironment>(),
+                1_000_000,
+            );
+            BetA0CoreTrait::set_operation_paused(&mut contract, PausableOperation::Rewards, true)
+                .unwrap();
+            assert!(BonusTrait::grant_bonus(&mut contract, player, 200, 300, 10_000).is_ok());
+            contract._credit_balance(player, 1000);
+
+            // a winning bonus bet locks its winnings
+            test::set_caller::<DefaultEnvironment>(player);
+            assert!(contract.play_with_bonus(50, 1, 0, 100).is_ok());
+            let multiplier = BetA0CoreTrait::get_bet(&contract, player)
+                .unwrap()
+                .multiplier;
+            let win_amount = multiplier as Balance * 100 / 10000;
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(contract.finalize(player, 99, Vec::new()).is_ok());
+            let bonus = BonusTrait::get_bonus(&contract, player);
+            assert_eq!(bonus.credits, 100);
+            assert_eq!(bonus.locked_winnings, win_amount);
+            assert_eq!(BonusTrait::get_total_bonus_locked(&contract), win_amount);
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 1000);
+
+            // bonus stakes count toward the wagering, not enough yet
+            test::set_caller::<DefaultEnvironment>(player);
+            assert!(contract.play_with_bonus(50, 1, 0, 100).is_ok());
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(contract.finalize(player, 10, Vec::new()).is_ok());
+            assert_eq!(BonusTrait::get_bonus(&contract, player).wagered, 200);
+            assert_eq!(BetA0CoreTrait::get_claimable(&contract, player), 1000);
+
+            // the stake that meets the requirement releases the winnings
+            test::set_caller::<DefaultEnvironment>(player);
+            assert!(contract.play_from_balance(50, 1, 0, 100).is_ok());
+            assert_eq!(BonusTrait::get_bonus(&contract, player).locked_winnings, 0);
+            assert_eq!(BonusTrait::get_total_bonus_locked(&contract), 0);
+            assert_eq!(
+                BetA0CoreTrait::get_claimable(&contract, player),
+                900 + win_amount
+            );
+        }
+
+        #[ink::test]
+        fn promo_codes_cannot_be_replayed_past_their_limits() {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let mut contract = new_contract();
+            let key = SecretKey::from_slice(&[9u8; 32]).unwrap();
+            let signer = PublicKey::from_secret_key(SECP256K1, &key).serialize();
+            assert!(PromoTrait::set_promo_signer(&mut contract, Some(signer)).is_ok());
+
+            let code = PromoCode {
+                code_id: 1,
+                contract: test::callee::<DefaultEnvironment>(),
+                reward: PromoReward::MultiplierBoost(1000),
+                limit: RedemptionLimit::PerAccountAndGlobal {
+                    per_account: 1,
+                    global: 2,
+                },
+                expires_at: 1000,
+            };
+            let signed = sign(&key, &code);
+
+            test::set_caller::<DefaultEnvironment>(accounts.frank);
+            assert!(PromoTrait::redeem_promo(&mut contract, code, signed).is_ok());
+            assert_eq!(
+                PromoTrait::get_multiplier_boost(&contract, accounts.frank),
+                1000
+            );
+            assert_eq!(
+                PromoTrait::redeem_promo(&mut contract, code, signed),
+                custom_error("O::Promo Limit Reached")
+            );
+
+            // the code is not valid on another deployment
+            let elsewhere = PromoCode {
+                contract: accounts.eve,
+                ..code
+            };
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                PromoTrait::redeem_promo(&mut contract, elsewhere, sign(&key, &elsewhere)),
+                custom_error("O::Invalid Promo Signature")
+            );
+
+            assert!(PromoTrait::redeem_promo(&mut contract, code, signed).is_ok());
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                PromoTrait::redeem_promo(&mut contract, code, signed),
+                custom_error("O::Promo Limit Reached")
+            );
+            assert_eq!(PromoTrait::get_promo_redemptions(&contract, 1), 2);
+            assert_eq!(
+                PromoTrait::get_account_promo_redemptions(&contract, 1, accounts.django),
+                0
+            );
+        }
+    }
 }
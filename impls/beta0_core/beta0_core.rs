@@ -1,15 +1,21 @@
 pub use crate::{
-    impls::beta0_core::{
-        beta0_core, data,
-        data::{Manager, *},
-        *,
+    impls::{
+        beta0_core::{
+            beta0_core, data,
+            data::{Manager, *},
+            *,
+        },
+        bonus::BonusTraitImpl,
+        eligibility::EligibilityTraitImpl,
+        responsible_gaming::{ResponsibleGamingTraitImpl, DEFAULT_LIMIT_INCREASE_DELAY},
+        timelock::data::{Timelock, DEFAULT_TIMELOCK_DELAY},
     },
     traits::beta0_core::*,
 };
 use ink::prelude::vec::Vec;
 use openbrush::{
-    contracts::{ownable::*, pausable::*, psp22::*},
-    traits::{AccountId, Balance, Storage, String},
+    contracts::{access_control::*, ownable::*, pausable::*, psp22::*},
+    traits::{AccountId, Balance, BlockNumber, Storage, String, Timestamp},
 };
 
 // Storage<data::Manager>
@@ -18,36 +24,214 @@ pub trait BetA0CoreTraitImpl:
     Storage<Manager>
     + Storage<pausable::Data>
     + Storage<ownable::Data>
+    + Storage<access_control::Data>
+    + Storage<Timelock>
     + pausable::Internal
     + ownable::Internal
+    + access_control::Internal
     + ownable::Ownable
     + pausable::Pausable
+    + AccessControl
+    + ResponsibleGamingTraitImpl
+    + EligibilityTraitImpl
+    + BonusTraitImpl
 {
     // Execute function
     /// Function changes state
     fn change_state(&mut self) -> Result<(), PausableError> {
         let caller = Self::env().caller();
-        if let Some(owner) = Ownable::owner(self) {
-            if caller != owner {
-                return Err(From::from(PausableError::Paused));
-            }
+        if !AccessControl::has_role(self, PAUSER, Some(caller)) {
+            return Err(From::from(PausableError::Paused));
+        }
 
-            if self._paused() {
-                self._unpause()
-            } else {
-                self._pause()
-            }
+        if self._paused() {
+            self._unpause()
         } else {
-            return Err(From::from(PausableError::Paused));
+            self._pause()
+        }
+    }
+
+    /// Run the storage migration steps after an upgrade
+    fn migrate(&mut self) -> Result<(), CoreError> {
+        let from_version = self
+            .data::<data::Manager>()
+            .storage_version
+            .get_or_default();
+        if from_version >= STORAGE_VERSION {
+            return Err(CoreError::Custom(String::from("O::Already Migrated")));
+        }
+
+        let mut version = from_version;
+        while version < STORAGE_VERSION {
+            self._migrate_step(version)?;
+            version += 1;
+            self.data::<data::Manager>().storage_version.set(&version);
+        }
+
+        self._emit_migrated_event(from_version, version);
+        Ok(())
+    }
+
+    /// Stop new bets for good and let players reclaim pending stakes
+    fn emergency_shutdown(&mut self) -> Result<(), CoreError> {
+        if self
+            .data::<data::Manager>()
+            .emergency_shutdown_at
+            .get_or_default()
+            .is_some()
+        {
+            return Err(CoreError::Custom(String::from("O::Already Shutdown")));
+        }
+
+        // the grace period is fixed from here on
+        let grace_period = self.get_emergency_grace_period();
+        let data_manager = self.data::<data::Manager>();
+        data_manager
+            .emergency_shutdown_at
+            .set(&Some(Self::env().block_timestamp()));
+        data_manager.emergency_grace_period.set(&grace_period);
+        self._emit_emergency_shutdown_event(grace_period);
+        Ok(())
+    }
+
+    /// Let bets in again after the circuit breaker tripped
+    fn reset_circuit_breaker(&mut self) -> Result<(), CoreError> {
+        let data_manager = self.data::<data::Manager>();
+        if !data_manager.breaker_tripped.get_or_default() {
+            return Err(CoreError::Custom(String::from(
+                "O::Circuit Breaker Not Tripped",
+            )));
+        }
+
+        data_manager.breaker_tripped.set(&false);
+        data_manager.breaker_buckets.set(&Vec::new());
+        self._emit_circuit_breaker_reset_event();
+        Ok(())
+    }
+
+    /// Reclaim the stake of a pending bet during emergency shutdown
+    fn refund_bet(&mut self) -> Result<(), CoreError> {
+        if self
+            .data::<data::Manager>()
+            .emergency_shutdown_at
+            .get_or_default()
+            .is_none()
+        {
+            return Err(CoreError::Custom(String::from("O::Not Shutdown")));
+        }
+
+        let player = Self::env().caller();
+        let bet_info = self
+            ._take_bet(player)
+            .ok_or(CoreError::Custom(String::from("O::Bet Not Exist")))?;
+
+        self._record_return(player, bet_info.bet_amount);
+        if bet_info.is_bonus {
+            self._return_bonus(player, bet_info.bet_amount);
+        } else {
+            self._release_escrow(bet_info.bet_amount);
+            self._pay_player(player, bet_info.bet_amount, bet_info.from_balance);
+        }
+
+        self._emit_bet_refunded_event(player, bet_info);
+        Ok(())
+    }
+
+    /// Pay out deferred wins up to what the block payout cap allows, the
+    /// rest stays deferred for a later block. Wins of bets staked from the
+    /// balance are credited back to it
+    fn release_deferred_payout(&mut self, player: AccountId) -> Result<(), CoreError> {
+        let deferred = self.get_deferred_payout(player);
+        if deferred == 0 {
+            return Err(CoreError::Custom(String::from("O::No Deferred Payout")));
+        }
+
+        // Release what the block payout cap still allows, the rest waits
+        // for a later block
+        let max_block_payout = self.get_max_block_payout();
+        let amount = if max_block_payout == 0 {
+            deferred
+        } else {
+            deferred.min(max_block_payout.saturating_sub(self.get_block_payout()))
+        };
+        if amount == 0 || !self._reserve_block_payout(amount) {
+            return Err(CoreError::Custom(String::from(
+                "O::Block Payout Cap Reached",
+            )));
+        }
+
+        let remaining = deferred.saturating_sub(amount);
+        let data_manager = self.data::<data::Manager>();
+        let to_balance = data_manager
+            .deferred_to_balance
+            .get(&player)
+            .unwrap_or_default();
+        if remaining == 0 {
+            data_manager.deferred_payouts.remove(&player);
+            data_manager.deferred_to_balance.remove(&player);
+        } else {
+            data_manager.deferred_payouts.insert(&player, &remaining);
+        }
+        data_manager.total_deferred_payouts.set(
+            &data_manager
+                .total_deferred_payouts
+                .get_or_default()
+                .saturating_sub(amount),
+        );
+
+        self._pay_player(player, amount, to_balance);
+        self._emit_deferred_payout_released_event(player, amount);
+        Ok(())
+    }
+
+    /// Withdraw the whole caller balance
+    fn claim(&mut self) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let amount = self.get_claimable(player);
+        if amount == 0 {
+            return Err(CoreError::Custom(String::from("O::Nothing To Claim")));
+        }
+
+        self._debit_balance(player, amount)?;
+        Self::env()
+            .transfer(player, amount)
+            .map_err(|_| CoreError::Custom(String::from("O::Transfer Failed")))?;
+        self._emit_claimed_event(player, amount);
+        Ok(())
+    }
+
+    /// Add the transferred native value to the caller balance, stakes and payouts are in the native token only
+    fn deposit(&mut self) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let amount = Self::env().transferred_value();
+        if amount == 0 {
+            return Err(CoreError::Custom(String::from("O::Invalid Input")));
+        }
+
+        let balance = self._credit_balance(player, amount);
+        self._emit_deposit_event(player, amount, balance);
+        Ok(())
+    }
+
+    /// Withdraw amount from the caller balance
+    fn withdraw(&mut self, amount: Balance) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        if amount == 0 {
+            return Err(CoreError::Custom(String::from("O::Invalid Input")));
         }
+
+        let balance = self._debit_balance(player, amount)?;
+        Self::env()
+            .transfer(player, amount)
+            .map_err(|_| CoreError::Custom(String::from("O::Transfer Failed")))?;
+        self._emit_withdraw_event(player, amount, balance);
+        Ok(())
     }
 
     /// tranfer token to pool
     fn tranfer_token_to_pool(&mut self, pool: AccountId, amount: Balance) -> Result<(), CoreError> {
         // state contract
-        if pausable::Internal::_paused(self) {
-            return Err(CoreError::Custom(String::from("P::Contract is paused")));
-        }
+        self._check_operation(PausableOperation::Treasury)?;
 
         let contract_balance = PSP22Ref::balance_of(
             &self.data::<data::Manager>().bet_token_address,
@@ -66,6 +250,12 @@ pub trait BetA0CoreTraitImpl:
             return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
         }
 
+        self._emit_transfer_token_to_pool_event(
+            pool,
+            amount,
+            contract_balance,
+            self.get_token_balance(),
+        );
         Ok(())
     }
 
@@ -75,61 +265,15 @@ pub trait BetA0CoreTraitImpl:
         player: AccountId,
         bet_amount: Balance,
     ) -> Result<(), CoreError> {
-        // state contract
-        if pausable::Internal::_paused(self) {
-            return Err(CoreError::Custom(String::from("P::Contract is paused")));
-        }
-
-        let data_manager = self.data::<data::Manager>();
-
-        let to_sent = bet_amount
-            .checked_div(data_manager.token_ratio as u128)
-            .unwrap();
-
-        let pool_balance =
-            PSP22Ref::balance_of(&data_manager.bet_token_address, data_manager.bet_pool);
-
-        // ensure the user gave allowance to the contract
-        if PSP22Ref::allowance(
-            &data_manager.bet_token_address,
-            data_manager.bet_pool,
-            Self::env().account_id(),
-        ) < to_sent
-        {
-            return Err(CoreError::Custom(String::from(
-                "InsufficientAllowanceToLend",
-            )));
-        }
-
-        if pool_balance >= to_sent {
-            assert!(PSP22Ref::transfer_from(
-                &data_manager.bet_token_address,
-                data_manager.bet_pool,
-                player,
-                to_sent,
-                Vec::<u8>::new()
-            )
-            .is_ok());
-        } else if pool_balance > 0 {
-            assert!(PSP22Ref::transfer_from(
-                &data_manager.bet_token_address,
-                data_manager.bet_pool,
-                player,
-                to_sent,
-                Vec::<u8>::new()
-            )
-            .is_ok());
-        }
-        //PSP22Ref::mint(&mut self.manager.psp22,player,bet_amount/ (self.manager.token_ratio as u256));
+        let token_ratio = self.data::<data::Manager>().token_ratio;
+        self._reward_token_to_player(player, bet_amount, token_ratio)?;
         Ok(())
     }
 
     /// Function reward token
     fn reward_token(&mut self, player: AccountId, bet_amount: Balance) -> Result<(), CoreError> {
         // state contract
-        if pausable::Internal::_paused(self) {
-            return Err(CoreError::Custom(String::from("P::Contract is paused")));
-        }
+        self._check_operation(PausableOperation::Rewards)?;
 
         let to_sent = bet_amount
             .checked_div(self.data::<data::Manager>().token_ratio as u128)
@@ -164,30 +308,26 @@ pub trait BetA0CoreTraitImpl:
     /// Withdraw Fees - only Owner
     fn withdraw_fee(&mut self, value: Balance) -> Result<(), CoreError> {
         // state contract
-        if pausable::Internal::_paused(self) {
-            return Err(CoreError::Custom(String::from("P::Contract is paused")));
-        }
+        self._check_operation(PausableOperation::Treasury)?;
+
+        self._check_bankroll_withdrawable()?;
 
-        if value > Self::env().balance() {
+        let old_balance = Self::env().balance();
+        if value > old_balance.saturating_sub(self._get_owed_to_players()) {
             return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
         }
         assert!(Self::env().transfer(Self::env().caller(), value).is_ok());
+        self._emit_withdraw_fee_event(value, old_balance, Self::env().balance());
         Ok(())
     }
 
     /// Withdraw Token - only Owner
     fn withdraw_token(&mut self, value: Balance) -> Result<(), CoreError> {
         // state contract
-        if pausable::Internal::_paused(self) {
-            return Err(CoreError::Custom(String::from("P::Contract is paused")));
-        }
+        self._check_operation(PausableOperation::Treasury)?;
 
-        if value
-            > PSP22Ref::balance_of(
-                &self.data::<data::Manager>().bet_token_address,
-                Self::env().account_id(),
-            )
-        {
+        let old_balance = self.get_token_balance();
+        if value > old_balance {
             return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
         }
         assert!(PSP22Ref::transfer(
@@ -197,91 +337,319 @@ pub trait BetA0CoreTraitImpl:
             Vec::<u8>::new()
         )
         .is_ok());
+        self._emit_withdraw_token_event(value, old_balance, self.get_token_balance());
         Ok(())
     }
 
     // Set Function
     /// Set min number over roll
     fn set_min_number_over_roll(&mut self, min_over_number: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().min_over_number;
         self.data::<data::Manager>().min_over_number = min_over_number;
+        self._validate_config()?;
+        self._emit_config_changed_event(ConfigParam::MinOverNumber, old_value, min_over_number);
         Ok(())
     }
 
     /// Set max number over roll
     fn set_max_number_over_roll(&mut self, max_over_number: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().max_over_number;
         self.data::<data::Manager>().max_over_number = max_over_number;
+        self._validate_config()?;
+        self._emit_config_changed_event(ConfigParam::MaxOverNumber, old_value, max_over_number);
         Ok(())
     }
 
     /// Set min number under roll
     fn set_min_number_under_roll(&mut self, min_under_number: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().min_under_number;
         self.data::<data::Manager>().min_under_number = min_under_number;
+        self._validate_config()?;
+        self._emit_config_changed_event(ConfigParam::MinUnderNumber, old_value, min_under_number);
         Ok(())
     }
 
     /// Set max number under roll
     fn set_max_number_under_roll(&mut self, max_under_number: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().max_under_number;
         self.data::<data::Manager>().max_under_number = max_under_number;
+        self._validate_config()?;
+        self._emit_config_changed_event(ConfigParam::MaxUnderNumber, old_value, max_under_number);
         Ok(())
     }
 
     /// Set over_rates and discount rate - Only Owner 2 vectors same size
     fn set_rates(&mut self, over_rates: Vec<u32>, under_rates: Vec<u32>) -> Result<(), CoreError> {
-        assert!(over_rates.len() == under_rates.len());
-        self.data::<data::Manager>().over_rates = over_rates;
-        self.data::<data::Manager>().under_rates = under_rates;
+        self._check_timelock()?;
+        self._set_rates(over_rates, under_rates)?;
+        self._validate_config()?;
+        Ok(())
+    }
+
+    /// Set how a roll equal to bet_number is settled
+    fn set_tie_policy(&mut self, tie_policy: TiePolicy) -> Result<(), CoreError> {
+        self._check_timelock()?;
+        self._set_tie_policy(tie_policy)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set new psp22 address
     fn set_bet_token_address(&mut self, bet_token_address: AccountId) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().bet_token_address;
         self.data::<data::Manager>().bet_token_address = bet_token_address;
+        self._validate_config()?;
+        self._emit_account_config_changed_event(
+            ConfigParam::BetTokenAddress,
+            old_value,
+            bet_token_address,
+        );
         Ok(())
     }
 
     /// Set new token ratio
     fn set_token_ratio(&mut self, token_ratio: u32) -> Result<(), CoreError> {
-        self.data::<data::Manager>().token_ratio = token_ratio;
+        self._check_timelock()?;
+        self._set_token_ratio(token_ratio)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set max bet ratio
     fn set_max_bet_ratio(&mut self, max_bet_ratio: u32) -> Result<(), CoreError> {
-        self.data::<data::Manager>().max_bet_ratio = max_bet_ratio;
+        self._check_timelock()?;
+        self._set_max_bet_ratio(max_bet_ratio)?;
+        self._validate_config()?;
         Ok(())
     }
 
-    /// Set revenue_ratio
+    /// Set revenue_ratio, replaces the revenue split with reward_pool and general_pool
     fn set_revenue_ratio(&mut self, revenue_ratio: u32) -> Result<(), CoreError> {
-        self.data::<data::Manager>().revenue_ratio = revenue_ratio;
+        self._check_timelock()?;
+        self._set_revenue_ratio(revenue_ratio)?;
+        self._validate_config()?;
+        Ok(())
+    }
+
+    /// Set how losing stakes are split
+    fn set_revenue_split(&mut self, revenue_split: Vec<RevenueShare>) -> Result<(), CoreError> {
+        self._check_timelock()?;
+        self._set_revenue_split(revenue_split)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set reward_pool
     fn set_reward_pool(&mut self, reward_pool: AccountId) -> Result<(), CoreError> {
-        self.data::<data::Manager>().reward_pool = reward_pool;
+        self._check_timelock()?;
+        self._set_reward_pool(reward_pool)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set max bet ratio
     fn set_general_pool(&mut self, general_pool: AccountId) -> Result<(), CoreError> {
-        self.data::<data::Manager>().general_pool = general_pool;
+        self._check_timelock()?;
+        self._set_general_pool(general_pool)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set bet_pool
     fn set_bet_pool(&mut self, bet_pool: AccountId) -> Result<(), CoreError> {
-        self.data::<data::Manager>().bet_pool = bet_pool;
+        self._check_timelock()?;
+        self._set_bet_pool(bet_pool)?;
+        self._validate_config()?;
         Ok(())
     }
 
     /// Set admin id
     fn set_admin_account(&mut self, admin_account: AccountId) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().admin_account;
         self.data::<data::Manager>().admin_account = admin_account;
+        access_control::Internal::_do_revoke_role(self, FINALIZER, Some(old_value));
+        access_control::Internal::_setup_role(self, FINALIZER, Some(admin_account));
+        self._validate_config()?;
+        self._emit_account_config_changed_event(
+            ConfigParam::AdminAccount,
+            old_value,
+            admin_account,
+        );
+        Ok(())
+    }
+
+    /// Pause or resume a single operation
+    fn set_operation_paused(
+        &mut self,
+        operation: PausableOperation,
+        paused: bool,
+    ) -> Result<(), CoreError> {
+        let flag = 1u8 << (operation as u8);
+        let data_manager = self.data::<data::Manager>();
+        let paused_operations = data_manager.paused_operations.get_or_default();
+        if paused {
+            data_manager
+                .paused_operations
+                .set(&(paused_operations | flag));
+        } else {
+            data_manager
+                .paused_operations
+                .set(&(paused_operations & !flag));
+        }
+        self._emit_operation_paused_event(operation, paused);
+        Ok(())
+    }
+
+    /// Set grace period after which the bankroll can be withdrawn in emergency shutdown
+    fn set_emergency_grace_period(
+        &mut self,
+        emergency_grace_period: Timestamp,
+    ) -> Result<(), CoreError> {
+        if self.is_emergency_shutdown() {
+            return Err(CoreError::Custom(String::from("O::Already Shutdown")));
+        }
+        if emergency_grace_period < MIN_EMERGENCY_GRACE_PERIOD {
+            return Err(CoreError::Custom(String::from("O::Grace Period Too Short")));
+        }
+
+        let old_value = self.get_emergency_grace_period();
+        self.data::<data::Manager>()
+            .emergency_grace_period
+            .set(&emergency_grace_period);
+        self._emit_timestamp_config_changed_event(
+            ConfigParam::EmergencyGracePeriod,
+            old_value,
+            emergency_grace_period,
+        );
+        Ok(())
+    }
+
+    /// Apply many settings at once, validated together
+    fn update_config(&mut self, update: GameConfigUpdate) -> Result<(), CoreError> {
+        if update.is_timelocked() {
+            self._check_timelock()?;
+        }
+        self._apply_config_update(update)?;
+        self._validate_config()?;
+        Ok(())
+    }
+
+    /// Set minimum bet
+    fn set_min_bet(&mut self, min_bet: Balance) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().min_bet.get_or_default();
+        self.data::<data::Manager>().min_bet.set(&min_bet);
+        self._validate_config()?;
+        self._emit_balance_config_changed_event(ConfigParam::MinBet, old_value, min_bet);
+        Ok(())
+    }
+
+    /// Set maximum payout of a single bet
+    fn set_max_payout(&mut self, max_payout: Balance) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().max_payout.get_or_default();
+        self.data::<data::Manager>().max_payout.set(&max_payout);
+        self._validate_config()?;
+        self._emit_balance_config_changed_event(ConfigParam::MaxPayout, old_value, max_payout);
+        Ok(())
+    }
+
+    /// Set maximum total payout per block
+    fn set_max_block_payout(&mut self, max_block_payout: Balance) -> Result<(), CoreError> {
+        let old_value = self
+            .data::<data::Manager>()
+            .max_block_payout
+            .get_or_default();
+        self.data::<data::Manager>()
+            .max_block_payout
+            .set(&max_block_payout);
+        self._validate_config()?;
+        self._emit_balance_config_changed_event(
+            ConfigParam::MaxBlockPayout,
+            old_value,
+            max_block_payout,
+        );
         Ok(())
     }
 
     // Get Function
+    /// get the whole game configuration in one call
+    fn get_config(&self) -> GameConfig {
+        let data_manager = self.data::<data::Manager>();
+        GameConfig {
+            min_over_number: data_manager.min_over_number,
+            max_over_number: data_manager.max_over_number,
+            min_under_number: data_manager.min_under_number,
+            max_under_number: data_manager.max_under_number,
+            over_rates: data_manager.over_rates.clone(),
+            under_rates: data_manager.under_rates.clone(),
+            tie_policy: data_manager.tie_policy.get_or_default(),
+            token_ratio: data_manager.token_ratio,
+            max_bet_ratio: data_manager.max_bet_ratio,
+            max_bet: self.get_max_bet(),
+            min_bet: data_manager.min_bet.get_or_default(),
+            max_payout: data_manager.max_payout.get_or_default(),
+            max_block_payout: data_manager.max_block_payout.get_or_default(),
+            revenue_ratio: data_manager.revenue_ratio,
+            revenue_split_id: data_manager.revenue_split_id.get_or_default(),
+            revenue_split: self.get_revenue_split(data_manager.revenue_split_id.get_or_default()),
+            bet_token_address: data_manager.bet_token_address,
+            reward_pool: data_manager.reward_pool,
+            general_pool: data_manager.general_pool,
+            bet_pool: data_manager.bet_pool,
+            admin_account: data_manager.admin_account,
+            rate_limits: data_manager.rate_limits.get_or_default(),
+            breaker_window: data_manager.breaker_window.get_or_default(),
+            breaker_max_loss_ratio: data_manager.breaker_max_loss_ratio.get_or_default(),
+            auto_push: data_manager.auto_push.get_or_default(),
+        }
+    }
+
+    /// Quote a bet of the caller without placing it, with any promo boost
+    /// waiting for the caller's next bet
+    fn quote(&self, is_over: u8, bet_number: u32, amount: Balance) -> BetQuote {
+        let player = Self::env().caller();
+        let data_manager = self.data::<data::Manager>();
+        let entry = self._get_payout_entry(is_over, bet_number);
+        let multiplier = if entry.multiplier > 0 {
+            entry
+                .multiplier
+                .saturating_add(self._get_multiplier_boost(player))
+        } else {
+            0
+        };
+
+        // saturating, a quote never fails whatever the amount
+        let payout = (multiplier as Balance).saturating_mul(amount) / 10000;
+        let reward_tokens = if self.is_operation_paused(PausableOperation::Rewards) {
+            0
+        } else {
+            amount
+                .checked_div(data_manager.token_ratio as u128)
+                .unwrap_or_default()
+        };
+
+        let mut max_bet = self.get_max_bet();
+        if data_manager.max_payout.get_or_default() > 0 && multiplier > 0 {
+            max_bet = max_bet.min(
+                data_manager
+                    .max_payout
+                    .get_or_default()
+                    .saturating_mul(10000)
+                    / multiplier as u128,
+            );
+        }
+
+        let rejection = self._check_bet(player, is_over, bet_number, amount).err();
+        BetQuote {
+            win_chance: entry.win_chance,
+            multiplier,
+            payout: self._cap_payout(payout, amount),
+            reward_tokens,
+            max_bet,
+            accepted: rejection.is_none(),
+            rejection,
+        }
+    }
+
     /// get min number over roll
     fn get_min_number_over_roll(&self) -> u32 {
         self.data::<data::Manager>().min_over_number
@@ -312,6 +680,21 @@ pub trait BetA0CoreTraitImpl:
         self.data::<data::Manager>().revenue_ratio
     }
 
+    /// get id of the current revenue split
+    fn get_revenue_split_id(&self) -> u32 {
+        self.data::<data::Manager>()
+            .revenue_split_id
+            .get_or_default()
+    }
+
+    /// get revenue split by id
+    fn get_revenue_split(&self, revenue_split_id: u32) -> Vec<RevenueShare> {
+        self.data::<data::Manager>()
+            .revenue_splits
+            .get(&revenue_split_id)
+            .unwrap_or_default()
+    }
+
     /// get reward pool
     fn get_reward_pool(&self) -> AccountId {
         self.data::<data::Manager>().reward_pool
@@ -348,9 +731,11 @@ pub trait BetA0CoreTraitImpl:
     }
 
     fn get_max_bet(&self) -> u128 {
-        (Self::env().balance())
-            .checked_div(self.data::<data::Manager>().max_bet_ratio as u128)
-            .unwrap()
+        (Self::env()
+            .balance()
+            .saturating_sub(self._get_owed_to_players()))
+        .checked_div(self.data::<data::Manager>().max_bet_ratio as u128)
+        .unwrap()
     }
 
     /// get contract token balance
@@ -368,8 +753,8 @@ pub trait BetA0CoreTraitImpl:
 
     /// Is bet exist
     fn is_bet_available(&self, player: AccountId) -> bool {
-        let bet_info = self.data::<data::Manager>().bets.get(&player);
-        bet_info.is_some()
+        let data_manager = self.data::<data::Manager>();
+        data_manager.open_bets.contains(&player) || data_manager.bets.contains(&player)
     }
 
     /// get admin id
@@ -379,10 +764,1402 @@ pub trait BetA0CoreTraitImpl:
 
     /// get bet
     fn get_bet(&self, player: AccountId) -> Option<BetInformation> {
-        let bet_info = self.data::<data::Manager>().bets.get(&player);
-        if let Some(_unwrapped_bet_info) = bet_info {
-            return Some(bet_info.unwrap());
+        let data_manager = self.data::<data::Manager>();
+        if let Some(bet_info) = data_manager.open_bets.get(&player) {
+            return Some(bet_info);
+        }
+        data_manager
+            .bets
+            .get(&player)
+            .map(|legacy_bet| self._legacy_bet(legacy_bet))
+    }
+
+    /// Send the bet reward from the bet pool, returns the amount sent
+    fn _reward_token_to_player(
+        &mut self,
+        player: AccountId,
+        bet_amount: Balance,
+        token_ratio: u32,
+    ) -> Result<Balance, CoreError> {
+        let to_sent = bet_amount.checked_div(token_ratio as u128).unwrap();
+        self._send_reward_tokens(player, to_sent)
+    }
+
+    /// Send BET tokens from the bet pool, capped at the pool balance,
+    /// returns the amount sent
+    fn _send_reward_tokens(
+        &mut self,
+        player: AccountId,
+        to_sent: Balance,
+    ) -> Result<Balance, CoreError> {
+        // state contract
+        self._check_operation(PausableOperation::Rewards)?;
+
+        let data_manager = self.data::<data::Manager>();
+
+        let pool_balance =
+            PSP22Ref::balance_of(&data_manager.bet_token_address, data_manager.bet_pool);
+
+        // ensure the user gave allowance to the contract
+        if PSP22Ref::allowance(
+            &data_manager.bet_token_address,
+            data_manager.bet_pool,
+            Self::env().account_id(),
+        ) < to_sent
+        {
+            return Err(CoreError::Custom(String::from(
+                "InsufficientAllowanceToLend",
+            )));
+        }
+
+        let sent = if pool_balance >= to_sent {
+            to_sent
+        } else {
+            pool_balance
+        };
+
+        if sent > 0
+            && PSP22Ref::transfer_from(
+                &data_manager.bet_token_address,
+                data_manager.bet_pool,
+                player,
+                sent,
+                Vec::<u8>::new(),
+            )
+            .is_err()
+        {
+            return Err(CoreError::Custom(String::from("O::Reward Transfer Failed")));
+        }
+        //PSP22Ref::mint(&mut self.manager.psp22,player,bet_amount/ (self.manager.token_ratio as u256));
+        Ok(sent)
+    }
+
+    /// Timelocked parameters can only be set directly while the delay is 0,
+    /// an unset delay is DEFAULT_TIMELOCK_DELAY
+    fn _check_timelock(&self) -> Result<(), CoreError> {
+        if self
+            .data::<Timelock>()
+            .delay
+            .get()
+            .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+            > 0
+        {
+            return Err(CoreError::Custom(String::from(
+                "O::Timelocked, Use Queue Change",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Apply revenue_ratio as a split between reward_pool and general_pool
+    fn _set_revenue_ratio(&mut self, revenue_ratio: u32) -> Result<(), CoreError> {
+        self._set_revenue_split(self._revenue_ratio_split(revenue_ratio))?;
+
+        let old_value = self.data::<data::Manager>().revenue_ratio;
+        self.data::<data::Manager>().revenue_ratio = revenue_ratio;
+        self._emit_config_changed_event(ConfigParam::RevenueRatio, old_value, revenue_ratio);
+        Ok(())
+    }
+
+    /// Validate and store a new revenue split, bets already placed keep theirs
+    fn _set_revenue_split(&mut self, revenue_split: Vec<RevenueShare>) -> Result<(), CoreError> {
+        let revenue_split_id = self._store_revenue_split(revenue_split.clone())?;
+        self._emit_revenue_split_changed_event(revenue_split_id, revenue_split);
+        Ok(())
+    }
+
+    /// Store a revenue split under a new id and make it current
+    fn _store_revenue_split(
+        &mut self,
+        revenue_split: Vec<RevenueShare>,
+    ) -> Result<u32, ConfigError> {
+        self._check_revenue_split(&revenue_split)?;
+
+        let data_manager = self.data::<data::Manager>();
+        let revenue_split_id = if data_manager
+            .revenue_splits
+            .contains(&data_manager.revenue_split_id.get_or_default())
+        {
+            data_manager
+                .revenue_split_id
+                .get_or_default()
+                .checked_add(1)
+                .unwrap()
+        } else {
+            data_manager.revenue_split_id.get_or_default()
+        };
+        data_manager
+            .revenue_splits
+            .insert(&revenue_split_id, &revenue_split);
+        data_manager.revenue_split_id.set(&revenue_split_id);
+        Ok(revenue_split_id)
+    }
+
+    /// Write the fields of a config update, the caller validates the result
+    fn _apply_config_update(&mut self, update: GameConfigUpdate) -> Result<(), CoreError> {
+        let data_manager = self.data::<data::Manager>();
+        if let Some(value) = update.min_over_number {
+            data_manager.min_over_number = value;
+        }
+        if let Some(value) = update.max_over_number {
+            data_manager.max_over_number = value;
         }
-        return None;
+        if let Some(value) = update.min_under_number {
+            data_manager.min_under_number = value;
+        }
+        if let Some(value) = update.max_under_number {
+            data_manager.max_under_number = value;
+        }
+        if let Some((over_rates, under_rates)) = update.rates.clone() {
+            data_manager.over_rates = over_rates;
+            data_manager.under_rates = under_rates;
+        }
+        if let Some(value) = update.tie_policy {
+            data_manager.tie_policy.set(&value);
+        }
+        if let Some(value) = update.token_ratio {
+            data_manager.token_ratio = value;
+        }
+        if let Some(value) = update.max_bet_ratio {
+            data_manager.max_bet_ratio = value;
+        }
+        if let Some(value) = update.min_bet {
+            data_manager.min_bet.set(&value);
+        }
+        if let Some(value) = update.max_payout {
+            data_manager.max_payout.set(&value);
+        }
+        if let Some(value) = update.max_block_payout {
+            data_manager.max_block_payout.set(&value);
+        }
+        if let Some(value) = update.bet_token_address {
+            data_manager.bet_token_address = value;
+        }
+        if let Some(value) = update.reward_pool {
+            data_manager.reward_pool = value;
+        }
+        if let Some(value) = update.general_pool {
+            data_manager.general_pool = value;
+        }
+        if let Some(value) = update.bet_pool {
+            data_manager.bet_pool = value;
+        }
+        if let Some(value) = update.rate_limits {
+            data_manager.rate_limits.set(&value);
+        }
+        if let Some((window, max_loss_ratio)) = update.circuit_breaker {
+            data_manager.breaker_window.set(&window);
+            data_manager.breaker_max_loss_ratio.set(&max_loss_ratio);
+        }
+        if let Some(value) = update.auto_push {
+            data_manager.auto_push.set(&value);
+        }
+        if let Some(value) = update.revenue_ratio {
+            data_manager.revenue_ratio = value;
+        }
+
+        if let Some(revenue_split) = update.revenue_split.clone() {
+            self._store_revenue_split(revenue_split)?;
+        } else if let Some(revenue_ratio) = update.revenue_ratio {
+            self._store_revenue_split(self._revenue_ratio_split(revenue_ratio))?;
+        }
+
+        self._emit_config_updated_event(update);
+        Ok(())
+    }
+
+    /// Shares must be non zero, sum to 10000 and pay to a set account
+    fn _check_revenue_split(&self, revenue_split: &[RevenueShare]) -> Result<(), ConfigError> {
+        if revenue_split.is_empty() || revenue_split.len() > MAX_REVENUE_SHARES {
+            return Err(ConfigError::InvalidRevenueSplit);
+        }
+        let mut total_bps: u32 = 0;
+        for share in revenue_split.iter() {
+            if share.bps == 0
+                || (share.kind != RevenueKind::Bankroll && share.recipient == [0u8; 32].into())
+            {
+                return Err(ConfigError::InvalidRevenueSplit);
+            }
+            total_bps = total_bps.saturating_add(share.bps);
+        }
+        if total_bps != 10000 {
+            return Err(ConfigError::RevenueSplitNotFull);
+        }
+        Ok(())
+    }
+
+    /// Invariants of the whole configuration, checked after every change
+    fn _validate_config(&self) -> Result<(), ConfigError> {
+        let data_manager = self.data::<data::Manager>();
+
+        if data_manager.token_ratio == 0 {
+            return Err(ConfigError::ZeroTokenRatio);
+        }
+        if data_manager.max_bet_ratio == 0 {
+            return Err(ConfigError::ZeroMaxBetRatio);
+        }
+        if data_manager.revenue_ratio > 100 {
+            return Err(ConfigError::InvalidRevenueRatio);
+        }
+
+        // rolls are 0 to 99, over needs a roll above and under a roll below
+        if data_manager.min_over_number > data_manager.max_over_number
+            || data_manager.max_over_number > 98
+        {
+            return Err(ConfigError::InvalidOverRange);
+        }
+        if data_manager.min_under_number > data_manager.max_under_number
+            || data_manager.min_under_number == 0
+            || data_manager.max_under_number > 99
+        {
+            return Err(ConfigError::InvalidUnderRange);
+        }
+        if data_manager.over_rates.len() != 100 || data_manager.under_rates.len() != 100 {
+            return Err(ConfigError::InvalidRatesLength);
+        }
+        for is_over in [0u8, 1u8] {
+            for entry in self.get_payout_table(is_over) {
+                if entry.multiplier == 0 {
+                    return Err(ConfigError::MissingRate(is_over, entry.bet_number));
+                }
+                if entry.rtp > 10000 {
+                    return Err(ConfigError::RateAboveFair(is_over, entry.bet_number));
+                }
+            }
+        }
+
+        for (param, account) in [
+            (ConfigParam::BetTokenAddress, data_manager.bet_token_address),
+            (ConfigParam::RewardPool, data_manager.reward_pool),
+            (ConfigParam::GeneralPool, data_manager.general_pool),
+            (ConfigParam::BetPool, data_manager.bet_pool),
+            (ConfigParam::AdminAccount, data_manager.admin_account),
+        ] {
+            if account == [0u8; 32].into() {
+                return Err(ConfigError::ZeroAddress(param));
+            }
+        }
+
+        if data_manager.max_payout.get_or_default() > 0
+            && data_manager.max_block_payout.get_or_default() > 0
+            && data_manager.max_block_payout.get_or_default()
+                < data_manager.max_payout.get_or_default()
+        {
+            return Err(ConfigError::InvalidPayoutCaps);
+        }
+        if data_manager.breaker_max_loss_ratio.get_or_default() > 10000 {
+            return Err(ConfigError::InvalidLossRatio);
+        }
+        if self._get_tie_refund() > 10000 {
+            return Err(ConfigError::InvalidTieRefund);
+        }
+
+        self._check_revenue_split(
+            &self.get_revenue_split(data_manager.revenue_split_id.get_or_default()),
+        )
+    }
+
+    /// Two way split of the original revenue_ratio percent, zero shares left out
+    fn _revenue_ratio_split(&self, revenue_ratio: u32) -> Vec<RevenueShare> {
+        let data_manager = self.data::<data::Manager>();
+        let reward_bps = revenue_ratio.min(100) * 100;
+        [
+            RevenueShare {
+                kind: RevenueKind::Reward,
+                recipient: data_manager.reward_pool,
+                bps: reward_bps,
+            },
+            RevenueShare {
+                kind: RevenueKind::Treasury,
+                recipient: data_manager.general_pool,
+                bps: 10000 - reward_bps,
+            },
+        ]
+        .into_iter()
+        .filter(|share| share.bps > 0)
+        .collect()
+    }
+
+    /// Apply tie policy
+    fn _set_tie_policy(&mut self, tie_policy: TiePolicy) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().tie_policy.get_or_default();
+        self.data::<data::Manager>().tie_policy.set(&tie_policy);
+        self._emit_tie_policy_changed_event(old_value, tie_policy);
+        Ok(())
+    }
+
+    /// Apply over_rates and under_rates
+    fn _set_rates(&mut self, over_rates: Vec<u32>, under_rates: Vec<u32>) -> Result<(), CoreError> {
+        let old_over_rates = self.data::<data::Manager>().over_rates.clone();
+        let old_under_rates = self.data::<data::Manager>().under_rates.clone();
+        self.data::<data::Manager>().over_rates = over_rates.clone();
+        self.data::<data::Manager>().under_rates = under_rates.clone();
+        self._emit_rates_changed_event(old_over_rates, over_rates, old_under_rates, under_rates);
+        Ok(())
+    }
+
+    /// Apply token ratio
+    fn _set_token_ratio(&mut self, token_ratio: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().token_ratio;
+        self.data::<data::Manager>().token_ratio = token_ratio;
+        self._emit_config_changed_event(ConfigParam::TokenRatio, old_value, token_ratio);
+        Ok(())
+    }
+
+    /// Apply max bet ratio
+    fn _set_max_bet_ratio(&mut self, max_bet_ratio: u32) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().max_bet_ratio;
+        self.data::<data::Manager>().max_bet_ratio = max_bet_ratio;
+        self._emit_config_changed_event(ConfigParam::MaxBetRatio, old_value, max_bet_ratio);
+        Ok(())
+    }
+
+    /// Apply reward_pool
+    fn _set_reward_pool(&mut self, reward_pool: AccountId) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().reward_pool;
+        self._move_revenue_shares(RevenueKind::Reward, old_value, reward_pool)?;
+        self.data::<data::Manager>().reward_pool = reward_pool;
+        self._emit_account_config_changed_event(ConfigParam::RewardPool, old_value, reward_pool);
+        Ok(())
+    }
+
+    /// Apply general_pool
+    fn _set_general_pool(&mut self, general_pool: AccountId) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().general_pool;
+        self._move_revenue_shares(RevenueKind::Treasury, old_value, general_pool)?;
+        self.data::<data::Manager>().general_pool = general_pool;
+        self._emit_account_config_changed_event(ConfigParam::GeneralPool, old_value, general_pool);
+        Ok(())
+    }
+
+    /// Shares of `kind` in the current revenue split paid to `old_pool`
+    /// go to `new_pool` under a new split, bets already placed keep theirs
+    fn _move_revenue_shares(
+        &mut self,
+        kind: RevenueKind,
+        old_pool: AccountId,
+        new_pool: AccountId,
+    ) -> Result<(), CoreError> {
+        let mut revenue_split = self.get_revenue_split(self.get_revenue_split_id());
+        let mut moved = false;
+        for share in revenue_split.iter_mut() {
+            if share.kind == kind && share.recipient == old_pool && old_pool != new_pool {
+                share.recipient = new_pool;
+                moved = true;
+            }
+        }
+        if moved {
+            self._set_revenue_split(revenue_split)?;
+        }
+        Ok(())
+    }
+
+    /// Apply bet_pool
+    fn _set_bet_pool(&mut self, bet_pool: AccountId) -> Result<(), CoreError> {
+        let old_value = self.data::<data::Manager>().bet_pool;
+        self.data::<data::Manager>().bet_pool = bet_pool;
+        self._emit_account_config_changed_event(ConfigParam::BetPool, old_value, bet_pool);
+        Ok(())
+    }
+
+    /// Reward sent while settling a bet at the token ratio of the bet,
+    /// nothing while rewards are paused. A failed reward never blocks the
+    /// settlement, it is reported with an event and nothing is sent.
+    fn _settle_reward(
+        &mut self,
+        player: AccountId,
+        bet_amount: Balance,
+        token_ratio: u32,
+    ) -> Balance {
+        if self.is_operation_paused(PausableOperation::Rewards) {
+            return 0;
+        }
+        match self._reward_token_to_player(player, bet_amount, token_ratio) {
+            Ok(sent) => sent,
+            Err(error) => {
+                self._emit_reward_failed_event(player, bet_amount, error);
+                0
+            }
+        }
+    }
+
+    /// Payout rate for a bet number, 0 if out of the rate table
+    fn _get_rate(&self, is_over: u8, bet_number: u32) -> u32 {
+        let data_manager = self.data::<data::Manager>();
+        let rates = if is_over == 1 {
+            &data_manager.over_rates
+        } else {
+            &data_manager.under_rates
+        };
+        rates.get(bet_number as usize).copied().unwrap_or_default()
+    }
+
+    /// Part of the stake in bps refunded on a tie
+    fn _get_tie_refund(&self) -> u32 {
+        match self.data::<data::Manager>().tie_policy.get_or_default() {
+            TiePolicy::HouseWins => 0,
+            TiePolicy::Push => 10000,
+            TiePolicy::PartialRefund(refund) => refund,
+        }
+    }
+
+    /// Send amount out by a revenue split, the Bankroll share and rounding
+    /// dust stay in the contract. A share that cannot be sent is credited
+    /// to the balance of its recipient to claim
+    fn _distribute_revenue(
+        &mut self,
+        amount: Balance,
+        revenue_split_id: u32,
+    ) -> Vec<RevenuePayout> {
+        let contract = Self::env().account_id();
+        let mut revenue = Vec::new();
+        for share in self.get_revenue_split(revenue_split_id) {
+            let share_amount = amount
+                .checked_mul(share.bps as u128)
+                .unwrap()
+                .checked_div(10000)
+                .unwrap();
+            let recipient = if share.kind == RevenueKind::Bankroll {
+                contract
+            } else {
+                if share_amount > 0 && Self::env().transfer(share.recipient, share_amount).is_err()
+                {
+                    let balance = self._credit_balance(share.recipient, share_amount);
+                    self._emit_revenue_transfer_failed_event(
+                        share.kind,
+                        share.recipient,
+                        share_amount,
+                    );
+                    self._emit_credited_event(share.recipient, share_amount, balance);
+                }
+                share.recipient
+            };
+            revenue.push(RevenuePayout {
+                kind: share.kind,
+                recipient,
+                amount: share_amount,
+            });
+        }
+        revenue
+    }
+
+    /// Part of distributed revenue that stays in the bankroll
+    fn _get_retained_revenue(&self, revenue: &[RevenuePayout]) -> Balance {
+        revenue
+            .iter()
+            .filter(|payout| payout.kind == RevenueKind::Bankroll)
+            .fold(0, |retained: Balance, payout| {
+                retained.saturating_add(payout.amount)
+            })
+    }
+
+    /// Payout of one bet number, rolls are 0 to 99
+    fn _get_payout_entry(&self, is_over: u8, bet_number: u32) -> PayoutEntry {
+        let win_chance = if is_over == 1 {
+            99u32.saturating_sub(bet_number)
+        } else {
+            bet_number.min(100)
+        };
+        let multiplier = self._get_rate(is_over, bet_number);
+        let tie_refund = self._get_tie_refund();
+        let rtp = (win_chance as u64 * multiplier as u64 + tie_refund as u64) / 100;
+        PayoutEntry {
+            bet_number,
+            win_chance,
+            multiplier,
+            tie_refund,
+            rtp: rtp as u32,
+        }
+    }
+
+    /// get tie policy
+    fn get_tie_policy(&self) -> TiePolicy {
+        self.data::<data::Manager>().tie_policy.get_or_default()
+    }
+
+    /// get return to player of a bet in bps
+    fn get_rtp(&self, is_over: u8, bet_number: u32) -> u32 {
+        self._get_payout_entry(is_over, bet_number).rtp
+    }
+
+    /// get payout of every allowed bet number on one side
+    fn get_payout_table(&self, is_over: u8) -> Vec<PayoutEntry> {
+        let data_manager = self.data::<data::Manager>();
+        let (min_number, max_number) = if is_over == 1 {
+            (data_manager.min_over_number, data_manager.max_over_number)
+        } else {
+            (data_manager.min_under_number, data_manager.max_under_number)
+        };
+        (min_number..=max_number)
+            .map(|bet_number| self._get_payout_entry(is_over, bet_number))
+            .collect()
+    }
+
+    /// get storage version
+    fn get_storage_version(&self) -> u32 {
+        self.data::<data::Manager>()
+            .storage_version
+            .get_or_default()
+    }
+
+    /// Is operation paused by its own switch
+    fn is_operation_paused(&self, operation: PausableOperation) -> bool {
+        self.data::<data::Manager>()
+            .paused_operations
+            .get_or_default()
+            & (1u8 << (operation as u8))
+            != 0
+    }
+
+    /// get operations paused by their own switch
+    fn get_paused_operations(&self) -> Vec<PausableOperation> {
+        [
+            PausableOperation::Betting,
+            PausableOperation::Settlement,
+            PausableOperation::Rewards,
+            PausableOperation::Treasury,
+        ]
+        .into_iter()
+        .filter(|operation| self.is_operation_paused(*operation))
+        .collect()
+    }
+
+    /// Operation runs only when neither the contract nor its switch is paused
+    fn _check_operation(&self, operation: PausableOperation) -> Result<(), CoreError> {
+        if pausable::Internal::_paused(self) {
+            return Err(CoreError::Custom(String::from("P::Contract is paused")));
+        }
+        if self.is_operation_paused(operation) {
+            return Err(CoreError::Custom(String::from("P::Operation is paused")));
+        }
+        Ok(())
+    }
+
+    /// Is emergency shutdown
+    fn is_emergency_shutdown(&self) -> bool {
+        self.data::<data::Manager>()
+            .emergency_shutdown_at
+            .get_or_default()
+            .is_some()
+    }
+
+    /// get emergency shutdown time
+    fn get_emergency_shutdown_at(&self) -> Option<Timestamp> {
+        self.data::<data::Manager>()
+            .emergency_shutdown_at
+            .get_or_default()
+    }
+
+    /// get emergency grace period
+    fn get_emergency_grace_period(&self) -> Timestamp {
+        self.data::<data::Manager>()
+            .emergency_grace_period
+            .get()
+            .unwrap_or(DEFAULT_EMERGENCY_GRACE_PERIOD)
+    }
+
+    /// get number of pending bets, bets placed before storage version 1
+    /// are not counted
+    fn get_pending_bets(&self) -> u32 {
+        self.data::<data::Manager>().pending_bets.get_or_default()
+    }
+
+    /// get total stake of pending bets
+    fn get_escrowed_stakes(&self) -> Balance {
+        self.data::<data::Manager>()
+            .escrowed_stakes
+            .get_or_default()
+    }
+
+    /// Set circuit breaker window in blocks and loss limit in bps of bankroll
+    fn set_circuit_breaker(
+        &mut self,
+        window: BlockNumber,
+        max_loss_ratio: u32,
+    ) -> Result<(), CoreError> {
+        let data_manager = self.data::<data::Manager>();
+        data_manager.breaker_window.set(&window);
+        data_manager.breaker_max_loss_ratio.set(&max_loss_ratio);
+        self._validate_config()?;
+        self._emit_circuit_breaker_config_changed_event(window, max_loss_ratio);
+        Ok(())
+    }
+
+    /// Send winnings of bets paid with a transfer straight to players and credit them only if the transfer fails
+    fn set_auto_push(&mut self, auto_push: bool) -> Result<(), CoreError> {
+        self.data::<data::Manager>().auto_push.set(&auto_push);
+        self._emit_auto_push_changed_event(auto_push);
+        Ok(())
+    }
+
+    /// Set bet rate limits
+    fn set_rate_limits(&mut self, rate_limits: RateLimits) -> Result<(), CoreError> {
+        self.data::<data::Manager>().rate_limits.set(&rate_limits);
+        self._emit_rate_limits_changed_event(rate_limits);
+        Ok(())
+    }
+
+    /// is circuit breaker tripped
+    fn is_circuit_breaker_tripped(&self) -> bool {
+        self.data::<data::Manager>()
+            .breaker_tripped
+            .get_or_default()
+    }
+
+    /// get circuit breaker window in blocks
+    fn get_circuit_breaker_window(&self) -> BlockNumber {
+        self.data::<data::Manager>().breaker_window.get_or_default()
+    }
+
+    /// get circuit breaker loss limit in bps of bankroll
+    fn get_circuit_breaker_max_loss_ratio(&self) -> u32 {
+        self.data::<data::Manager>()
+            .breaker_max_loss_ratio
+            .get_or_default()
+    }
+
+    /// get net house loss over the last circuit breaker window blocks
+    fn get_window_house_loss(&self) -> Balance {
+        self._get_house_loss(&self._get_breaker_buckets())
+    }
+
+    /// get bet rate limits
+    fn get_rate_limits(&self) -> RateLimits {
+        self.data::<data::Manager>().rate_limits.get_or_default()
+    }
+
+    /// get bet rate counters of player
+    fn get_player_bet_rate(&self, player: AccountId) -> PlayerBetRate {
+        self.data::<data::Manager>()
+            .player_bet_rates
+            .get(&player)
+            .unwrap_or_default()
+    }
+
+    /// get number of bets placed in the current block
+    fn get_block_bets(&self) -> u32 {
+        let data_manager = self.data::<data::Manager>();
+        if data_manager.bets_block.get_or_default() == Self::env().block_number() {
+            data_manager.bets_in_block.get_or_default()
+        } else {
+            0
+        }
+    }
+
+    /// get balance of player, deposits and unclaimed winnings and refunds
+    fn get_claimable(&self, player: AccountId) -> Balance {
+        self.data::<data::Manager>()
+            .claimable
+            .get(&player)
+            .unwrap_or_default()
+    }
+
+    /// get balance of all players
+    fn get_total_claimable(&self) -> Balance {
+        self.data::<data::Manager>()
+            .total_claimable
+            .get_or_default()
+    }
+
+    /// is auto push enabled
+    fn is_auto_push(&self) -> bool {
+        self.data::<data::Manager>().auto_push.get_or_default()
+    }
+
+    /// Pay a player, amounts that are not pushed are credited to their
+    /// balance. Auto push is skipped for bets staked from the balance
+    fn _pay_player(&mut self, player: AccountId, amount: Balance, to_balance: bool) {
+        if amount == 0 {
+            return;
+        }
+        if !to_balance
+            && self.data::<data::Manager>().auto_push.get_or_default()
+            && Self::env().transfer(player, amount).is_ok()
+        {
+            return;
+        }
+
+        let claimable = self._credit_balance(player, amount);
+        self._emit_credited_event(player, amount, claimable);
+    }
+
+    /// Add amount to the balance of player, returns the new balance
+    fn _credit_balance(&mut self, player: AccountId, amount: Balance) -> Balance {
+        let balance = self.get_claimable(player).checked_add(amount).unwrap();
+        let data_manager = self.data::<data::Manager>();
+        data_manager.claimable.insert(&player, &balance);
+        data_manager.total_claimable.set(
+            &data_manager
+                .total_claimable
+                .get_or_default()
+                .checked_add(amount)
+                .unwrap(),
+        );
+        balance
+    }
+
+    /// Take amount from the balance of player, returns the new balance
+    fn _debit_balance(&mut self, player: AccountId, amount: Balance) -> Result<Balance, CoreError> {
+        let balance = self
+            .get_claimable(player)
+            .checked_sub(amount)
+            .ok_or(CoreError::Custom(String::from("O::Not Enough Balance")))?;
+        let data_manager = self.data::<data::Manager>();
+        if balance == 0 {
+            data_manager.claimable.remove(&player);
+        } else {
+            data_manager.claimable.insert(&player, &balance);
+        }
+        data_manager.total_claimable.set(
+            &data_manager
+                .total_claimable
+                .get_or_default()
+                .saturating_sub(amount),
+        );
+        Ok(balance)
+    }
+
+    /// Balance held for players and their escrowed stakes, not part of
+    /// the bankroll
+    fn _get_owed_to_players(&self) -> Balance {
+        let data_manager = self.data::<data::Manager>();
+        data_manager
+            .total_claimable
+            .get_or_default()
+            .checked_add(data_manager.total_deferred_payouts.get_or_default())
+            .unwrap()
+            .checked_add(data_manager.escrowed_stakes.get_or_default())
+            .unwrap()
+            .checked_add(self.get_total_bonus_locked())
+            .unwrap()
+    }
+
+    /// Count a bet towards the bonus wagering requirement and credit
+    /// the winnings it unlocks to the player balance
+    fn _progress_bonus(&mut self, player: AccountId, amount: Balance) {
+        let unlocked = self._record_bonus_wager(player, amount);
+        self._credit_unlocked_bonus(player, unlocked);
+    }
+
+    /// Credit unlocked bonus winnings to the player balance
+    fn _credit_unlocked_bonus(&mut self, player: AccountId, unlocked: Balance) {
+        if unlocked > 0 {
+            let balance = self._credit_balance(player, unlocked);
+            self._emit_credited_event(player, unlocked, balance);
+        }
+    }
+
+    /// get minimum bet
+    fn get_min_bet(&self) -> Balance {
+        self.data::<data::Manager>().min_bet.get_or_default()
+    }
+
+    /// get maximum payout of a single bet
+    fn get_max_payout(&self) -> Balance {
+        self.data::<data::Manager>().max_payout.get_or_default()
+    }
+
+    /// get maximum total payout per block
+    fn get_max_block_payout(&self) -> Balance {
+        self.data::<data::Manager>()
+            .max_block_payout
+            .get_or_default()
+    }
+
+    /// get total paid out in the current block
+    fn get_block_payout(&self) -> Balance {
+        let data_manager = self.data::<data::Manager>();
+        if data_manager.payout_block.get_or_default() == Self::env().block_number() {
+            data_manager.block_payout.get_or_default()
+        } else {
+            0
+        }
+    }
+
+    /// get deferred payout of player
+    fn get_deferred_payout(&self, player: AccountId) -> Balance {
+        self.data::<data::Manager>()
+            .deferred_payouts
+            .get(&player)
+            .unwrap_or_default()
+    }
+
+    /// Bet is rejected if its win at multiplier would be over the single
+    /// payout cap
+    fn _check_max_payout(&self, multiplier: u32, bet_amount: Balance) -> Result<(), CoreError> {
+        let max_payout = self.data::<data::Manager>().max_payout.get_or_default();
+        let win_amount = (multiplier as Balance).saturating_mul(bet_amount) / 10000;
+        if max_payout > 0 && win_amount > max_payout {
+            return Err(CoreError::Custom(String::from("O::Payout Above Maximum")));
+        }
+        Ok(())
+    }
+
+    /// Cap a win at the single payout cap, the stake is always returned
+    fn _cap_payout(&self, win_amount: Balance, bet_amount: Balance) -> Balance {
+        let max_payout = self.data::<data::Manager>().max_payout.get_or_default();
+        if max_payout > 0 {
+            win_amount.min(max_payout.max(bet_amount))
+        } else {
+            win_amount
+        }
+    }
+
+    /// Count amount against the block payout cap, false if it does not fit
+    fn _reserve_block_payout(&mut self, amount: Balance) -> bool {
+        let block_number = Self::env().block_number();
+        let block_payout = self.get_block_payout();
+        let data_manager = self.data::<data::Manager>();
+
+        let new_block_payout = block_payout.checked_add(amount).unwrap();
+        if data_manager.max_block_payout.get_or_default() > 0
+            && new_block_payout > data_manager.max_block_payout.get_or_default()
+        {
+            return false;
+        }
+
+        data_manager.payout_block.set(&block_number);
+        data_manager.block_payout.set(&new_block_payout);
+        true
+    }
+
+    /// Keep a win for release in a later block
+    fn _defer_payout(&mut self, player: AccountId, amount: Balance, to_balance: bool) {
+        let deferred = self
+            .get_deferred_payout(player)
+            .checked_add(amount)
+            .unwrap();
+        let data_manager = self.data::<data::Manager>();
+        data_manager.deferred_payouts.insert(&player, &deferred);
+        if to_balance {
+            data_manager.deferred_to_balance.insert(&player, &true);
+        }
+        data_manager.total_deferred_payouts.set(
+            &data_manager
+                .total_deferred_payouts
+                .get_or_default()
+                .checked_add(amount)
+                .unwrap(),
+        );
+        self._emit_payout_deferred_event(player, amount);
+    }
+
+    /// Store the pending bet of player
+    fn _store_bet(&mut self, player: AccountId, bet_info: &BetInformation) {
+        let data_manager = self.data::<data::Manager>();
+        data_manager.open_bets.insert(&player, bet_info);
+        data_manager.pending_bets.set(
+            &data_manager
+                .pending_bets
+                .get_or_default()
+                .checked_add(1)
+                .unwrap(),
+        );
+    }
+
+    /// Remove the pending bet of player for settlement or refund
+    fn _take_bet(&mut self, player: AccountId) -> Option<BetInformation> {
+        let data_manager = self.data::<data::Manager>();
+        if let Some(bet_info) = data_manager.open_bets.get(&player) {
+            data_manager.open_bets.remove(&player);
+            data_manager
+                .pending_bets
+                .set(&data_manager.pending_bets.get_or_default().saturating_sub(1));
+            return Some(bet_info);
+        }
+
+        let legacy_bet = data_manager.bets.get(&player)?;
+        data_manager.bets.remove(&player);
+        // the stake was never escrowed, lock it so settling releases it
+        // like any other stake
+        self._lock_escrow(legacy_bet.bet_amount);
+        Some(self._legacy_bet(legacy_bet))
+    }
+
+    /// Bet of the first release at the live terms it was settled with
+    fn _legacy_bet(&self, legacy_bet: LegacyBetInformation) -> BetInformation {
+        let data_manager = self.data::<data::Manager>();
+        BetInformation {
+            is_over: legacy_bet.is_over,
+            bet_number: legacy_bet.bet_number,
+            bet_amount: legacy_bet.bet_amount,
+            bet_id: LEGACY_BET_ID,
+            placed_at: 0,
+            multiplier: self._get_rate(legacy_bet.is_over, legacy_bet.bet_number),
+            revenue_split_id: data_manager.revenue_split_id.get_or_default(),
+            token_ratio: data_manager.token_ratio,
+            tie_refund: 0,
+            is_bonus: false,
+            from_balance: false,
+        }
+    }
+
+    /// Track a new pending stake
+    fn _lock_escrow(&mut self, bet_amount: Balance) {
+        let data_manager = self.data::<data::Manager>();
+        data_manager.escrowed_stakes.set(
+            &data_manager
+                .escrowed_stakes
+                .get_or_default()
+                .checked_add(bet_amount)
+                .unwrap(),
+        );
+    }
+
+    /// Release a settled or refunded stake
+    fn _release_escrow(&mut self, bet_amount: Balance) {
+        let data_manager = self.data::<data::Manager>();
+        data_manager.escrowed_stakes.set(
+            &data_manager
+                .escrowed_stakes
+                .get_or_default()
+                .saturating_sub(bet_amount),
+        );
+    }
+
+    /// In emergency shutdown the bankroll stays locked until every stake is
+    /// refunded or the grace period has passed
+    fn _check_bankroll_withdrawable(&self) -> Result<(), CoreError> {
+        let data_manager = self.data::<data::Manager>();
+        if let Some(shutdown_at) = data_manager.emergency_shutdown_at.get_or_default() {
+            let grace_end = shutdown_at.saturating_add(self.get_emergency_grace_period());
+            if data_manager.escrowed_stakes.get_or_default() > 0
+                && Self::env().block_timestamp() < grace_end
+            {
+                return Err(CoreError::Custom(String::from("O::Stakes Still Escrowed")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Every check play makes before taking a bet, without side effects
+    fn _check_bet(
+        &self,
+        player: AccountId,
+        is_over: u8,
+        bet_number: u32,
+        bet_amount: Balance,
+    ) -> Result<(), CoreError> {
+        // state contract
+        self._check_operation(PausableOperation::Betting)?;
+        self._check_migrated()?;
+        self._check_not_shutdown()?;
+        self._check_circuit_breaker()?;
+
+        let data_manager = self.data::<data::Manager>();
+        if !(1..=self.get_max_bet()).contains(&bet_amount) {
+            return Err(CoreError::Custom(String::from("O::Invalid Bet Amount")));
+        }
+        if bet_amount < data_manager.min_bet.get_or_default() {
+            return Err(CoreError::Custom(String::from("O::Bet Below Minimum")));
+        }
+
+        let in_range = if is_over == 1 {
+            (data_manager.min_over_number..=data_manager.max_over_number).contains(&bet_number)
+        } else if is_over == 0 {
+            (data_manager.min_under_number..=data_manager.max_under_number).contains(&bet_number)
+        } else {
+            false
+        };
+        if !in_range {
+            return Err(CoreError::Custom(String::from("O::Invalid Input")));
+        }
+
+        if self.is_bet_available(player) {
+            return Err(CoreError::Custom(String::from("O::Bet Not Finalized")));
+        }
+
+        EligibilityTraitImpl::check_eligibility(self, player)?;
+        self._check_player_limits(player, bet_amount)?;
+        self._check_bet_rate(player)?;
+        self._check_max_payout(
+            self._get_multiplier(player, is_over, bet_number)?,
+            bet_amount,
+        )
+    }
+
+    /// Rate of the next bet of player with any promo boost added
+    fn _get_multiplier(
+        &self,
+        player: AccountId,
+        is_over: u8,
+        bet_number: u32,
+    ) -> Result<u32, CoreError> {
+        self._get_rate(is_over, bet_number)
+            .checked_add(self._get_multiplier_boost(player))
+            .ok_or(CoreError::Custom(String::from("O::Boost Above Maximum")))
+    }
+
+    /// Promo boost waiting for the next bet of player, none without promos
+    fn _get_multiplier_boost(&self, _player: AccountId) -> u32 {
+        0
+    }
+
+    /// Bet rate counters of player after one more bet now
+    fn _next_bet_rate(&self, player: AccountId) -> PlayerBetRate {
+        let block_number = Self::env().block_number();
+        let window = self
+            .data::<data::Manager>()
+            .rate_limits
+            .get_or_default()
+            .window;
+
+        let mut rate = self.get_player_bet_rate(player);
+        if window == 0 || block_number >= rate.window_start.saturating_add(window) {
+            rate.window_start = block_number;
+            rate.bets_in_window = 0;
+        }
+        rate.bets_in_window = rate.bets_in_window.checked_add(1).unwrap();
+        rate.last_bet_block = Some(block_number);
+        rate
+    }
+
+    /// Check the bet rate limits
+    fn _check_bet_rate(&self, player: AccountId) -> Result<(), CoreError> {
+        let block_number = Self::env().block_number();
+        let limits = self.data::<data::Manager>().rate_limits.get_or_default();
+
+        let block_bets = self.get_block_bets().checked_add(1).unwrap();
+        if limits.max_bets_per_block > 0 && block_bets > limits.max_bets_per_block {
+            return Err(CoreError::Custom(String::from(
+                "O::Block Bet Limit Reached",
+            )));
+        }
+
+        if let Some(last_bet_block) = self.get_player_bet_rate(player).last_bet_block {
+            if block_number < last_bet_block.saturating_add(limits.cooldown) {
+                return Err(CoreError::Custom(String::from("O::Bet Cooldown")));
+            }
+        }
+        let rate = self._next_bet_rate(player);
+        if limits.max_bets_per_window > 0 && rate.bets_in_window > limits.max_bets_per_window {
+            return Err(CoreError::Custom(String::from("O::Bet Rate Limit Reached")));
+        }
+        Ok(())
+    }
+
+    /// Count a bet against the bet rate limits
+    fn _record_bet_rate(&mut self, player: AccountId) {
+        let block_number = Self::env().block_number();
+        let rate = self._next_bet_rate(player);
+        let block_bets = self.get_block_bets().checked_add(1).unwrap();
+
+        let data_manager = self.data::<data::Manager>();
+        data_manager.player_bet_rates.insert(&player, &rate);
+        data_manager.bets_block.set(&block_number);
+        data_manager.bets_in_block.set(&block_bets);
+    }
+
+    /// No new bets while the circuit breaker is tripped
+    fn _check_circuit_breaker(&self) -> Result<(), CoreError> {
+        if self
+            .data::<data::Manager>()
+            .breaker_tripped
+            .get_or_default()
+        {
+            return Err(CoreError::Custom(String::from(
+                "O::Circuit Breaker Tripped",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Blocks covered by one circuit breaker bucket
+    fn _get_breaker_bucket_len(&self) -> BlockNumber {
+        (self.data::<data::Manager>().breaker_window.get_or_default() / BREAKER_BUCKETS).max(1)
+    }
+
+    /// Buckets still in the window of the last breaker_window blocks, a
+    /// tripped breaker keeps the ones that tripped it until reset
+    fn _get_breaker_buckets(&self) -> Vec<BreakerBucket> {
+        let data_manager = self.data::<data::Manager>();
+        let buckets = data_manager.breaker_buckets.get_or_default();
+        if data_manager.breaker_tripped.get_or_default() {
+            return buckets;
+        }
+
+        let window_start = Self::env()
+            .block_number()
+            .saturating_add(1)
+            .saturating_sub(data_manager.breaker_window.get_or_default());
+        let bucket_len = self._get_breaker_bucket_len();
+        buckets
+            .into_iter()
+            .filter(|bucket| bucket.start.saturating_add(bucket_len) > window_start)
+            .collect()
+    }
+
+    /// Net house loss of the buckets
+    fn _get_house_loss(&self, buckets: &[BreakerBucket]) -> Balance {
+        let (paid, received) =
+            buckets
+                .iter()
+                .fold((0 as Balance, 0 as Balance), |(paid, received), bucket| {
+                    (
+                        paid.saturating_add(bucket.paid),
+                        received.saturating_add(bucket.received),
+                    )
+                });
+        paid.saturating_sub(received)
+    }
+
+    /// Add a settled bet to the loss window and trip the breaker when net
+    /// house losses over the last breaker_window blocks pass max_loss_ratio
+    /// of the bankroll, balances owed to players are not bankroll
+    fn _record_house_result(&mut self, paid: Balance, received: Balance) {
+        let data_manager = self.data::<data::Manager>();
+        let max_loss_ratio = data_manager.breaker_max_loss_ratio.get_or_default();
+        if max_loss_ratio == 0 || data_manager.breaker_tripped.get_or_default() {
+            return;
+        }
+
+        let block_number = Self::env().block_number();
+        let bucket_start = block_number - block_number % self._get_breaker_bucket_len();
+        let mut buckets = self._get_breaker_buckets();
+        match buckets.last_mut() {
+            Some(bucket) if bucket.start == bucket_start => {
+                bucket.paid = bucket.paid.saturating_add(paid);
+                bucket.received = bucket.received.saturating_add(received);
+            }
+            _ => buckets.push(BreakerBucket {
+                start: bucket_start,
+                paid,
+                received,
+            }),
+        }
+
+        let house_loss = self._get_house_loss(&buckets);
+        let window_start = buckets.first().map_or(bucket_start, |bucket| bucket.start);
+        let bankroll = Self::env()
+            .balance()
+            .saturating_sub(self._get_owed_to_players());
+        let max_loss = bankroll.saturating_mul(max_loss_ratio as u128) / 10000;
+        self.data::<data::Manager>().breaker_buckets.set(&buckets);
+        if house_loss > max_loss {
+            self.data::<data::Manager>().breaker_tripped.set(&true);
+            self._emit_circuit_breaker_tripped_event(window_start, house_loss, bankroll);
+        }
+    }
+
+    /// No new bets or settlements once shut down
+    fn _check_not_shutdown(&self) -> Result<(), CoreError> {
+        if self
+            .data::<data::Manager>()
+            .emergency_shutdown_at
+            .get_or_default()
+            .is_some()
+        {
+            return Err(CoreError::Custom(String::from("O::Emergency Shutdown")));
+        }
+        Ok(())
+    }
+
+    /// Migrate storage from `version` to `version + 1`
+    fn _migrate_step(&mut self, version: u32) -> Result<(), CoreError> {
+        match version {
+            // v1: from the first release. The caller gets the roles and
+            // defaults of a new deployment, finalize is gated by the FINALIZER
+            // role instead of admin_account and losing stakes follow a revenue
+            // split seeded from revenue_ratio. Bets placed before stay in
+            // `bets` and settle at the live terms.
+            0 => {
+                self._init_defaults(Self::env().caller())?;
+                let admin_account = self.data::<data::Manager>().admin_account;
+                access_control::Internal::_setup_role(self, FINALIZER, Some(admin_account));
+                let revenue_ratio = self.data::<data::Manager>().revenue_ratio;
+                self._set_revenue_split(self._revenue_ratio_split(revenue_ratio))
+            }
+            _ => Err(CoreError::Custom(String::from(
+                "O::Unknown Storage Version",
+            ))),
+        }
+    }
+
+    /// Roles and defaults of a new deployment, `owner` administers the roles
+    fn _init_defaults(&mut self, owner: AccountId) -> Result<(), CoreError> {
+        access_control::Internal::_init_with_admin(self, Some(owner));
+        for role in [CONFIGURATOR, TREASURER, PAUSER, PROMOTER] {
+            access_control::Internal::_setup_role(self, role, Some(owner));
+        }
+        ResponsibleGamingTraitImpl::set_limit_increase_delay(self, DEFAULT_LIMIT_INCREASE_DELAY)
+    }
+
+    /// Upgrades wait until no bet is pending and no stake is escrowed, so
+    /// the new code never decodes a bet stored with another layout
+    fn _check_upgradable(&self) -> Result<(), CoreError> {
+        if self.get_pending_bets() > 0 || self.get_escrowed_stakes() > 0 {
+            return Err(CoreError::Custom(String::from("O::Bets Pending")));
+        }
+        Ok(())
+    }
+
+    /// Play and finalize are blocked until `migrate` has run
+    fn _check_migrated(&self) -> Result<(), CoreError> {
+        if self
+            .data::<data::Manager>()
+            .storage_version
+            .get_or_default()
+            != STORAGE_VERSION
+        {
+            return Err(CoreError::Custom(String::from("O::Migration Pending")));
+        }
+        Ok(())
+    }
+
+    // Events
+    /// Emit event when storage is migrated
+    fn _emit_migrated_event(&self, _from_version: u32, _to_version: u32) {}
+
+    /// Emit event when an operation switch changes
+    fn _emit_operation_paused_event(&self, _operation: PausableOperation, _paused: bool) {}
+
+    /// Emit event when emergency shutdown starts
+    fn _emit_emergency_shutdown_event(&self, _grace_period: Timestamp) {}
+
+    /// Emit event when a player reclaims a stake
+    fn _emit_bet_refunded_event(&self, _player: AccountId, _bet_info: BetInformation) {}
+
+    /// Emit event when winnings or a refund are credited for claim
+    fn _emit_credited_event(&self, _player: AccountId, _amount: Balance, _claimable: Balance) {}
+
+    /// Emit event when a player deposits to their balance
+    fn _emit_deposit_event(&self, _player: AccountId, _amount: Balance, _balance: Balance) {}
+
+    /// Emit event when a player withdraws from their balance
+    fn _emit_withdraw_event(&self, _player: AccountId, _amount: Balance, _balance: Balance) {}
+
+    /// Emit event when a player claims credited winnings
+    fn _emit_claimed_event(&self, _player: AccountId, _amount: Balance) {}
+
+    /// Emit event when auto push is switched
+    fn _emit_auto_push_changed_event(&self, _auto_push: bool) {}
+
+    /// Emit event when many settings change in one update
+    fn _emit_config_updated_event(&self, _update: GameConfigUpdate) {}
+
+    /// Emit event when the revenue split changes
+    fn _emit_revenue_split_changed_event(
+        &self,
+        _revenue_split_id: u32,
+        _revenue_split: Vec<RevenueShare>,
+    ) {
+    }
+
+    /// Emit event when the tie policy changes
+    fn _emit_tie_policy_changed_event(&self, _old_value: TiePolicy, _new_value: TiePolicy) {}
+
+    /// Emit event when the bet rate limits change
+    fn _emit_rate_limits_changed_event(&self, _rate_limits: RateLimits) {}
+
+    /// Emit event when the circuit breaker trips
+    fn _emit_circuit_breaker_tripped_event(
+        &self,
+        _window_start: BlockNumber,
+        _house_loss: Balance,
+        _bankroll: Balance,
+    ) {
+    }
+
+    /// Emit event when the circuit breaker is reset
+    fn _emit_circuit_breaker_reset_event(&self) {}
+
+    /// Emit event when the circuit breaker settings change
+    fn _emit_circuit_breaker_config_changed_event(
+        &self,
+        _window: BlockNumber,
+        _max_loss_ratio: u32,
+    ) {
+    }
+
+    /// Emit event when a revenue share could not be sent and was credited
+    fn _emit_revenue_transfer_failed_event(
+        &self,
+        _kind: RevenueKind,
+        _recipient: AccountId,
+        _amount: Balance,
+    ) {
+    }
+
+    /// Emit event when the reward of a settled bet could not be sent
+    fn _emit_reward_failed_event(
+        &self,
+        _player: AccountId,
+        _bet_amount: Balance,
+        _error: CoreError,
+    ) {
+    }
+
+    /// Emit event when a timestamp setting changes
+    fn _emit_timestamp_config_changed_event(
+        &self,
+        _param: ConfigParam,
+        _old_value: Timestamp,
+        _new_value: Timestamp,
+    ) {
+    }
+
+    /// Emit event when a balance setting changes
+    fn _emit_balance_config_changed_event(
+        &self,
+        _param: ConfigParam,
+        _old_value: Balance,
+        _new_value: Balance,
+    ) {
+    }
+
+    /// Emit event when a win is deferred by the block payout cap
+    fn _emit_payout_deferred_event(&self, _player: AccountId, _amount: Balance) {}
+
+    /// Emit event when a deferred win is paid out
+    fn _emit_deferred_payout_released_event(&self, _player: AccountId, _amount: Balance) {}
+
+    /// Emit event when a numeric setting changes
+    fn _emit_config_changed_event(&self, _param: ConfigParam, _old_value: u32, _new_value: u32) {}
+
+    /// Emit event when an account setting changes
+    fn _emit_account_config_changed_event(
+        &self,
+        _param: ConfigParam,
+        _old_value: AccountId,
+        _new_value: AccountId,
+    ) {
+    }
+
+    /// Emit event when the payout tables change
+    fn _emit_rates_changed_event(
+        &self,
+        _old_over_rates: Vec<u32>,
+        _new_over_rates: Vec<u32>,
+        _old_under_rates: Vec<u32>,
+        _new_under_rates: Vec<u32>,
+    ) {
+    }
+
+    /// Emit event when fees are withdrawn
+    fn _emit_withdraw_fee_event(
+        &self,
+        _value: Balance,
+        _old_balance: Balance,
+        _new_balance: Balance,
+    ) {
+    }
+
+    /// Emit event when tokens are withdrawn
+    fn _emit_withdraw_token_event(
+        &self,
+        _value: Balance,
+        _old_balance: Balance,
+        _new_balance: Balance,
+    ) {
+    }
+
+    /// Emit event when tokens are sent to a pool
+    fn _emit_transfer_token_to_pool_event(
+        &self,
+        _pool: AccountId,
+        _amount: Balance,
+        _old_balance: Balance,
+        _new_balance: Balance,
+    ) {
     }
 }
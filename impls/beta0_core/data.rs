@@ -1,10 +1,32 @@
 use ink::prelude::vec::Vec;
 use ink::storage::Mapping;
-use openbrush::traits::{AccountId, Balance};
+use openbrush::{
+    contracts::access_control::RoleType,
+    traits::{AccountId, Balance, BlockNumber, Timestamp},
+};
+
+use crate::traits::beta0_core::CoreError;
 
 #[cfg(feature = "std")]
 use ink::storage::traits::StorageLayout;
 
+/// Bet placed before storage version 1, kept in `Manager::bets`
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct LegacyBetInformation {
+    pub is_over: u8,
+    pub bet_number: u32,
+    pub bet_amount: Balance,
+}
+
+/// `bet_id` of a bet placed before bet ids existed
+pub const LEGACY_BET_ID: u64 = u64::MAX;
+
+/// Pending bet, kept in `Manager::open_bets`. `set_code_hash` refuses to
+/// run while bets are pending, so a layout change only needs a storage
+/// version bump.
 #[derive(
     Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
 )]
@@ -13,10 +35,284 @@ pub struct BetInformation {
     pub is_over: u8,
     pub bet_number: u32,
     pub bet_amount: Balance,
+    pub bet_id: u64,
+    pub placed_at: BlockNumber,
+    /// Terms locked in at play, settlement does not read the live values
+    pub multiplier: u32,
+    pub revenue_split_id: u32,
+    pub token_ratio: u32,
+    pub tie_refund: u32,
+    /// Paid with bonus credits, winnings stay locked until wagering is met
+    pub is_bonus: bool,
+    /// Staked from the internal balance, winnings and refunds go back to it
+    pub from_balance: bool,
+}
+
+/// Where the stake of a new bet comes from
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum BetFunding {
+    /// Transferred with the call
+    Wallet,
+    /// Debited from the internal balance
+    Balance,
+    /// Spent from bonus credits
+    Bonus,
+}
+
+/// How a roll equal to `bet_number` is settled
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum TiePolicy {
+    /// The stake goes to the pools like a loss
+    #[default]
+    HouseWins,
+    /// The whole stake is refunded
+    Push,
+    /// This part of the stake in bps is refunded, the rest goes to the pools
+    PartialRefund(u32),
+}
+
+/// Payout of one bet number, rates and refunds in bps
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PayoutEntry {
+    pub bet_number: u32,
+    /// Winning rolls out of 100
+    pub win_chance: u32,
+    pub multiplier: u32,
+    pub tie_refund: u32,
+    pub rtp: u32,
+}
+
+/// Destination of a share of losing stakes
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum RevenueKind {
+    /// Kept in the contract to grow the bankroll, the recipient is ignored
+    Bankroll,
+    Reward,
+    Treasury,
+    Charity,
+    Buyback,
+}
+
+/// One share of the revenue split in bps
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct RevenueShare {
+    pub kind: RevenueKind,
+    pub recipient: AccountId,
+    pub bps: u32,
+}
+
+/// Amount sent for one share of the revenue split
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct RevenuePayout {
+    pub kind: RevenueKind,
+    pub recipient: AccountId,
+    pub amount: Balance,
+}
+
+/// Maximum number of shares in a revenue split
+pub const MAX_REVENUE_SHARES: usize = 8;
+
+/// The circuit breaker window rolls in this many buckets
+pub const BREAKER_BUCKETS: BlockNumber = 10;
+
+/// House result settled in the blocks of one circuit breaker bucket
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct BreakerBucket {
+    pub start: BlockNumber,
+    pub paid: Balance,
+    pub received: Balance,
+}
+
+/// Everything a frontend needs to render the game
+#[derive(Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct GameConfig {
+    pub min_over_number: u32,
+    pub max_over_number: u32,
+    pub min_under_number: u32,
+    pub max_under_number: u32,
+    pub over_rates: Vec<u32>,
+    pub under_rates: Vec<u32>,
+    pub tie_policy: TiePolicy,
+    pub token_ratio: u32,
+    pub max_bet_ratio: u32,
+    pub max_bet: Balance,
+    pub min_bet: Balance,
+    pub max_payout: Balance,
+    pub max_block_payout: Balance,
+    pub revenue_ratio: u32,
+    pub revenue_split_id: u32,
+    pub revenue_split: Vec<RevenueShare>,
+    pub bet_token_address: AccountId,
+    pub reward_pool: AccountId,
+    pub general_pool: AccountId,
+    pub bet_pool: AccountId,
+    pub admin_account: AccountId,
+    pub rate_limits: RateLimits,
+    pub breaker_window: BlockNumber,
+    pub breaker_max_loss_ratio: u32,
+    pub auto_push: bool,
+}
+
+/// Fields of `GameConfig` to change, `None` keeps the current value.
+/// A revenue_split takes precedence over the split built from revenue_ratio.
+#[derive(Clone, Debug, Default, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct GameConfigUpdate {
+    pub min_over_number: Option<u32>,
+    pub max_over_number: Option<u32>,
+    pub min_under_number: Option<u32>,
+    pub max_under_number: Option<u32>,
+    pub rates: Option<(Vec<u32>, Vec<u32>)>,
+    pub tie_policy: Option<TiePolicy>,
+    pub token_ratio: Option<u32>,
+    pub max_bet_ratio: Option<u32>,
+    pub min_bet: Option<Balance>,
+    pub max_payout: Option<Balance>,
+    pub max_block_payout: Option<Balance>,
+    pub revenue_ratio: Option<u32>,
+    pub revenue_split: Option<Vec<RevenueShare>>,
+    pub bet_token_address: Option<AccountId>,
+    pub reward_pool: Option<AccountId>,
+    pub general_pool: Option<AccountId>,
+    pub bet_pool: Option<AccountId>,
+    pub rate_limits: Option<RateLimits>,
+    pub circuit_breaker: Option<(BlockNumber, u32)>,
+    pub auto_push: Option<bool>,
 }
 
+impl GameConfigUpdate {
+    /// Touches a field that has its own timelocked setter
+    pub fn is_timelocked(&self) -> bool {
+        self.rates.is_some()
+            || self.tie_policy.is_some()
+            || self.token_ratio.is_some()
+            || self.max_bet_ratio.is_some()
+            || self.revenue_ratio.is_some()
+            || self.revenue_split.is_some()
+            || self.reward_pool.is_some()
+            || self.general_pool.is_some()
+            || self.bet_pool.is_some()
+    }
+}
+
+/// Outcome of a bet as `play` and `finalize` would compute it now
+#[derive(Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct BetQuote {
+    /// Winning rolls out of 100
+    pub win_chance: u32,
+    pub multiplier: u32,
+    /// Paid on a win, stake included and capped by max_payout
+    pub payout: Balance,
+    /// BET tokens at token_ratio before the bet pool balance cap
+    pub reward_tokens: Balance,
+    /// Largest bet accepted on this target
+    pub max_bet: Balance,
+    pub accepted: bool,
+    /// Why the bet would be rejected
+    pub rejection: Option<CoreError>,
+}
+
+/// Operator bet rate limits, a zero field disables that limit
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct RateLimits {
+    /// Length of the per player window in blocks
+    pub window: BlockNumber,
+    /// Bets a player may place within one window
+    pub max_bets_per_window: u32,
+    /// Blocks a player waits between two bets
+    pub cooldown: BlockNumber,
+    /// Bets all players together may place in one block
+    pub max_bets_per_block: u32,
+}
+
+/// Bet counters of a player, kept in one mapping entry
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PlayerBetRate {
+    pub window_start: BlockNumber,
+    pub bets_in_window: u32,
+    pub last_bet_block: Option<BlockNumber>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum ConfigParam {
+    MinOverNumber,
+    MaxOverNumber,
+    MinUnderNumber,
+    MaxUnderNumber,
+    TokenRatio,
+    MaxBetRatio,
+    RevenueRatio,
+    BetTokenAddress,
+    RewardPool,
+    GeneralPool,
+    BetPool,
+    AdminAccount,
+    MinBet,
+    MaxPayout,
+    MaxBlockPayout,
+    EmergencyGracePeriod,
+    LimitIncreaseDelay,
+}
+
+/// Operations that can be paused on their own
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum PausableOperation {
+    /// Accepting new bets with `play`
+    Betting,
+    /// Settling bets with `finalize`
+    Settlement,
+    /// BET token rewards
+    Rewards,
+    /// Fee and token withdrawals and pool transfers
+    Treasury,
+}
+
+/// May settle bets with `finalize` and send bet rewards
+pub const FINALIZER: RoleType = ink::selector_id!("FINALIZER");
+/// May withdraw fees and tokens and fund pools
+pub const TREASURER: RoleType = ink::selector_id!("TREASURER");
+/// May change rates, limits, ratios and pools
+pub const CONFIGURATOR: RoleType = ink::selector_id!("CONFIGURATOR");
+/// May pause and unpause the contract
+pub const PAUSER: RoleType = ink::selector_id!("PAUSER");
+/// May grant and revoke bonus credits
+pub const PROMOTER: RoleType = ink::selector_id!("PROMOTER");
+
+/// Layout version of `PlayEvent`, `WinEvent`, `LoseEvent` and `PushEvent`
+pub const GAME_EVENT_VERSION: u8 = 5;
+
+/// Shortest grace period of an emergency shutdown, one day
+pub const MIN_EMERGENCY_GRACE_PERIOD: Timestamp = 86_400_000;
+/// Grace period until one is set, seven days
+pub const DEFAULT_EMERGENCY_GRACE_PERIOD: Timestamp = 7 * MIN_EMERGENCY_GRACE_PERIOD;
+
+/// Storage layout version of this code, see `migrate`
+pub const STORAGE_VERSION: u32 = 1;
+
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Manager);
 
+/// The fields up to `max_under_number` and `_reserved` are the packed root
+/// layout of the first release. Everything added since is lazy or a
+/// mapping, so the root cell still decodes after `set_code_hash`.
 #[derive(Debug)]
 #[openbrush::storage_item]
 pub struct Manager {
@@ -25,7 +321,7 @@ pub struct Manager {
     pub max_bet_ratio: u32,
     pub bet_token_address: AccountId,
     pub token_ratio: u32,
-    pub bets: Mapping<AccountId, BetInformation>,
+    pub bets: Mapping<AccountId, LegacyBetInformation>,
     pub admin_account: AccountId,
     pub revenue_ratio: u32,
     pub reward_pool: AccountId,
@@ -35,6 +331,60 @@ pub struct Manager {
     pub max_over_number: u32,
     pub min_under_number: u32,
     pub max_under_number: u32,
+    #[lazy]
+    pub storage_version: u32,
+    #[lazy]
+    pub bet_counter: u64,
+    #[lazy]
+    pub pending_bets: u32,
+    #[lazy]
+    pub escrowed_stakes: Balance,
+    #[lazy]
+    pub emergency_shutdown_at: Option<Timestamp>,
+    #[lazy]
+    pub emergency_grace_period: Timestamp,
+    #[lazy]
+    pub paused_operations: u8,
+    #[lazy]
+    pub min_bet: Balance,
+    #[lazy]
+    pub max_payout: Balance,
+    #[lazy]
+    pub max_block_payout: Balance,
+    #[lazy]
+    pub payout_block: BlockNumber,
+    #[lazy]
+    pub block_payout: Balance,
+    #[lazy]
+    pub total_deferred_payouts: Balance,
+    #[lazy]
+    pub breaker_window: BlockNumber,
+    #[lazy]
+    pub breaker_max_loss_ratio: u32,
+    #[lazy]
+    pub breaker_buckets: Vec<BreakerBucket>,
+    #[lazy]
+    pub breaker_tripped: bool,
+    #[lazy]
+    pub rate_limits: RateLimits,
+    #[lazy]
+    pub bets_block: BlockNumber,
+    #[lazy]
+    pub bets_in_block: u32,
+    #[lazy]
+    pub tie_policy: TiePolicy,
+    #[lazy]
+    pub total_claimable: Balance,
+    #[lazy]
+    pub auto_push: bool,
+    #[lazy]
+    pub revenue_split_id: u32,
+    pub open_bets: Mapping<AccountId, BetInformation>,
+    pub deferred_payouts: Mapping<AccountId, Balance>,
+    pub deferred_to_balance: Mapping<AccountId, bool>,
+    pub player_bet_rates: Mapping<AccountId, PlayerBetRate>,
+    pub claimable: Mapping<AccountId, Balance>,
+    pub revenue_splits: Mapping<u32, Vec<RevenueShare>>,
     pub _reserved: Option<()>,
 }
 
@@ -56,6 +406,36 @@ impl Default for Manager {
             max_over_number: Default::default(),
             min_under_number: Default::default(),
             max_under_number: Default::default(),
+            storage_version: Default::default(),
+            bet_counter: Default::default(),
+            pending_bets: Default::default(),
+            escrowed_stakes: Default::default(),
+            emergency_shutdown_at: Default::default(),
+            emergency_grace_period: Default::default(),
+            paused_operations: Default::default(),
+            min_bet: Default::default(),
+            max_payout: Default::default(),
+            max_block_payout: Default::default(),
+            payout_block: Default::default(),
+            block_payout: Default::default(),
+            total_deferred_payouts: Default::default(),
+            breaker_window: Default::default(),
+            breaker_max_loss_ratio: Default::default(),
+            breaker_buckets: Default::default(),
+            breaker_tripped: Default::default(),
+            rate_limits: Default::default(),
+            bets_block: Default::default(),
+            bets_in_block: Default::default(),
+            tie_policy: Default::default(),
+            total_claimable: Default::default(),
+            auto_push: Default::default(),
+            revenue_split_id: Default::default(),
+            open_bets: Default::default(),
+            deferred_payouts: Default::default(),
+            deferred_to_balance: Default::default(),
+            player_bet_rates: Default::default(),
+            claimable: Default::default(),
+            revenue_splits: Default::default(),
             _reserved: Default::default(),
         }
     }
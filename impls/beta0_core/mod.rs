@@ -1,3 +1,3 @@
-pub mod data;
 pub mod beta0_core;
+pub mod data;
 pub use beta0_core::*;
@@ -0,0 +1,242 @@
+pub use crate::{
+    impls::bonus::{
+        bonus, data,
+        data::{Bonus, *},
+    },
+    traits::{beta0_core::CoreError, bonus::*},
+};
+use openbrush::traits::{AccountId, Balance, Storage, String, Timestamp};
+
+pub trait BonusTraitImpl: Storage<Bonus> {
+    // Execute function
+    /// Grant bonus credits to player, a new grant adds to the current bonus
+    fn grant_bonus(
+        &mut self,
+        player: AccountId,
+        credits: Balance,
+        wagering_required: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), CoreError> {
+        if credits == 0 || expires_at <= Self::env().block_timestamp() {
+            return Err(CoreError::Custom(String::from("O::Invalid Input")));
+        }
+        self._forfeit_if_expired(player);
+
+        let mut bonus = self.get_bonus(player);
+        bonus.credits = bonus.credits.checked_add(credits).unwrap();
+        bonus.wagering_required = bonus
+            .wagering_required
+            .checked_add(wagering_required)
+            .unwrap();
+        bonus.expires_at = bonus.expires_at.max(expires_at);
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        let total_credits = self
+            .data::<Bonus>()
+            .total_credits
+            .get_or_default()
+            .checked_add(credits)
+            .unwrap();
+        self.data::<Bonus>().total_credits.set(&total_credits);
+
+        self._emit_bonus_granted_event(player, credits, wagering_required, bonus.expires_at);
+        Ok(())
+    }
+
+    /// Remove the bonus of player
+    fn revoke_bonus(&mut self, player: AccountId) -> Result<(), CoreError> {
+        if !self.data::<Bonus>().accounts.contains(&player) {
+            return Err(CoreError::Custom(String::from("O::No Bonus")));
+        }
+        self._forfeit_bonus(player);
+        Ok(())
+    }
+
+    /// Forfeit an expired bonus
+    fn forfeit_expired_bonus(&mut self, player: AccountId) -> Result<(), CoreError> {
+        if !self._forfeit_if_expired(player) {
+            return Err(CoreError::Custom(String::from("O::Bonus Not Expired")));
+        }
+        Ok(())
+    }
+
+    // Get Function
+    /// get bonus of player, empty once expired
+    fn get_bonus(&self, player: AccountId) -> BonusAccount {
+        self.data::<Bonus>()
+            .accounts
+            .get(&player)
+            .filter(|bonus| Self::env().block_timestamp() < bonus.expires_at)
+            .unwrap_or_default()
+    }
+
+    /// get bonus credits of all players
+    fn get_total_bonus_credits(&self) -> Balance {
+        self.data::<Bonus>().total_credits.get_or_default()
+    }
+
+    /// get locked bonus winnings of all players
+    fn get_total_bonus_locked(&self) -> Balance {
+        self.data::<Bonus>().total_locked_winnings.get_or_default()
+    }
+
+    /// Take credits for a bonus bet
+    fn _spend_bonus(&mut self, player: AccountId, amount: Balance) -> Result<(), CoreError> {
+        self._forfeit_if_expired(player);
+
+        let mut bonus = self.get_bonus(player);
+        bonus.credits = bonus
+            .credits
+            .checked_sub(amount)
+            .ok_or(CoreError::Custom(String::from("O::Not Enough Bonus")))?;
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        let total_credits = self
+            .data::<Bonus>()
+            .total_credits
+            .get_or_default()
+            .saturating_sub(amount);
+        self.data::<Bonus>().total_credits.set(&total_credits);
+        Ok(())
+    }
+
+    /// Give credits of a refunded or pushed bonus bet back, lost if the bonus expired
+    fn _return_bonus(&mut self, player: AccountId, amount: Balance) {
+        if self._forfeit_if_expired(player) || !self.data::<Bonus>().accounts.contains(&player) {
+            return;
+        }
+
+        let mut bonus = self.get_bonus(player);
+        bonus.credits = bonus.credits.checked_add(amount).unwrap();
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        let total_credits = self
+            .data::<Bonus>()
+            .total_credits
+            .get_or_default()
+            .checked_add(amount)
+            .unwrap();
+        self.data::<Bonus>().total_credits.set(&total_credits);
+    }
+
+    /// Lock winnings of a bonus bet, returns the winnings unlocked by it
+    fn _lock_bonus_winnings(&mut self, player: AccountId, amount: Balance) -> Balance {
+        if self._forfeit_if_expired(player) || !self.data::<Bonus>().accounts.contains(&player) {
+            return 0;
+        }
+
+        let mut bonus = self.get_bonus(player);
+        bonus.locked_winnings = bonus.locked_winnings.checked_add(amount).unwrap();
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        let total_locked_winnings = self
+            .data::<Bonus>()
+            .total_locked_winnings
+            .get_or_default()
+            .checked_add(amount)
+            .unwrap();
+        self.data::<Bonus>()
+            .total_locked_winnings
+            .set(&total_locked_winnings);
+        self._unlock_bonus_winnings(player)
+    }
+
+    /// Count a bet towards the wagering requirement, returns the winnings it unlocks
+    fn _record_bonus_wager(&mut self, player: AccountId, amount: Balance) -> Balance {
+        if self._forfeit_if_expired(player) || !self.data::<Bonus>().accounts.contains(&player) {
+            return 0;
+        }
+
+        let mut bonus = self.get_bonus(player);
+        bonus.wagered = bonus.wagered.saturating_add(amount);
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        self._emit_bonus_wagered_event(player, bonus.wagered, bonus.wagering_required);
+        self._unlock_bonus_winnings(player)
+    }
+
+    /// Release locked winnings once the wagering requirement is met
+    fn _unlock_bonus_winnings(&mut self, player: AccountId) -> Balance {
+        let mut bonus = self.get_bonus(player);
+        if bonus.wagered < bonus.wagering_required || bonus.locked_winnings == 0 {
+            return 0;
+        }
+
+        let unlocked = bonus.locked_winnings;
+        bonus.locked_winnings = 0;
+        self.data::<Bonus>().accounts.insert(&player, &bonus);
+        let total_locked_winnings = self
+            .data::<Bonus>()
+            .total_locked_winnings
+            .get_or_default()
+            .saturating_sub(unlocked);
+        self.data::<Bonus>()
+            .total_locked_winnings
+            .set(&total_locked_winnings);
+        self._emit_bonus_unlocked_event(player, unlocked);
+        unlocked
+    }
+
+    /// Forfeit the bonus if it expired, true if it did
+    fn _forfeit_if_expired(&mut self, player: AccountId) -> bool {
+        match self.data::<Bonus>().accounts.get(&player) {
+            Some(bonus) if Self::env().block_timestamp() >= bonus.expires_at => {
+                self._forfeit_bonus(player);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop the bonus of player with its credits and locked winnings
+    fn _forfeit_bonus(&mut self, player: AccountId) {
+        let bonus = self
+            .data::<Bonus>()
+            .accounts
+            .get(&player)
+            .unwrap_or_default();
+        self.data::<Bonus>().accounts.remove(&player);
+        let total_credits = self
+            .data::<Bonus>()
+            .total_credits
+            .get_or_default()
+            .saturating_sub(bonus.credits);
+        self.data::<Bonus>().total_credits.set(&total_credits);
+        let total_locked_winnings = self
+            .data::<Bonus>()
+            .total_locked_winnings
+            .get_or_default()
+            .saturating_sub(bonus.locked_winnings);
+        self.data::<Bonus>()
+            .total_locked_winnings
+            .set(&total_locked_winnings);
+        self._emit_bonus_forfeited_event(player, bonus.credits, bonus.locked_winnings);
+    }
+
+    // Events
+    /// Emit event when bonus credits are granted
+    fn _emit_bonus_granted_event(
+        &self,
+        _player: AccountId,
+        _credits: Balance,
+        _wagering_required: Balance,
+        _expires_at: Timestamp,
+    ) {
+    }
+
+    /// Emit event when a bet counts towards the wagering requirement
+    fn _emit_bonus_wagered_event(
+        &self,
+        _player: AccountId,
+        _wagered: Balance,
+        _wagering_required: Balance,
+    ) {
+    }
+
+    /// Emit event when locked bonus winnings move to the real balance
+    fn _emit_bonus_unlocked_event(&self, _player: AccountId, _amount: Balance) {}
+
+    /// Emit event when a bonus is revoked or expires
+    fn _emit_bonus_forfeited_event(
+        &self,
+        _player: AccountId,
+        _credits: Balance,
+        _locked_winnings: Balance,
+    ) {
+    }
+}
@@ -0,0 +1,43 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Bonus of a player, kept apart from real funds. Credits can only be
+/// wagered, winnings of bonus bets unlock to the real balance once
+/// `wagered` reaches `wagering_required`. Everything left is forfeited
+/// at `expires_at`.
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct BonusAccount {
+    pub credits: Balance,
+    pub locked_winnings: Balance,
+    pub wagering_required: Balance,
+    pub wagered: Balance,
+    pub expires_at: Timestamp,
+}
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Bonus);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Bonus {
+    pub accounts: Mapping<AccountId, BonusAccount>,
+    #[lazy]
+    pub total_credits: Balance,
+    #[lazy]
+    pub total_locked_winnings: Balance,
+}
+
+impl Default for Bonus {
+    fn default() -> Self {
+        Self {
+            accounts: Default::default(),
+            total_credits: Default::default(),
+            total_locked_winnings: Default::default(),
+        }
+    }
+}
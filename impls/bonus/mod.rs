@@ -0,0 +1,3 @@
+pub mod bonus;
+pub mod data;
+pub use bonus::*;
@@ -0,0 +1,73 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Timestamp};
+
+use crate::impls::signature::SignedPayload;
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Off-chain check (KYC, jurisdiction) signed by the attestation signer
+/// for `account` on `contract`
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct Attestation {
+    pub account: AccountId,
+    pub contract: AccountId,
+    pub expires_at: Timestamp,
+}
+
+impl SignedPayload for Attestation {
+    const TYPE_TAG: &'static [u8] = b"Attestation";
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum EligibilityList {
+    Deny,
+    Allow,
+}
+
+/// Switch of the eligibility checks
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum EligibilitySetting {
+    AllowListEnabled,
+    AttestationRequired,
+}
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Eligibility);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Eligibility {
+    pub denied: Mapping<AccountId, bool>,
+    #[lazy]
+    pub allow_list_enabled: bool,
+    pub allowed: Mapping<AccountId, bool>,
+    #[lazy]
+    pub attestation_required: bool,
+    #[lazy]
+    pub attestation_signer: Option<[u8; 33]>,
+    /// Bumped on every signer change, attestations of an older epoch no
+    /// longer count
+    #[lazy]
+    pub attestation_epoch: u32,
+    /// Expiry of the attestation of an account, by (epoch, account)
+    pub attestations: Mapping<(u32, AccountId), Timestamp>,
+}
+
+impl Default for Eligibility {
+    fn default() -> Self {
+        Self {
+            denied: Default::default(),
+            allow_list_enabled: Default::default(),
+            allowed: Default::default(),
+            attestation_required: Default::default(),
+            attestation_signer: Default::default(),
+            attestation_epoch: Default::default(),
+            attestations: Default::default(),
+        }
+    }
+}
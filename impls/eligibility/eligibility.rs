@@ -0,0 +1,217 @@
+pub use crate::{
+    impls::{
+        eligibility::{
+            data,
+            data::{Eligibility, *},
+            eligibility,
+        },
+        signature,
+    },
+    traits::{beta0_core::CoreError, eligibility::*},
+};
+use openbrush::traits::{AccountId, Storage, Timestamp};
+
+pub trait EligibilityTraitImpl: Storage<Eligibility> {
+    // Execute function
+    /// Store a signed attestation for its account, an attestation expiring
+    /// no later than the stored one changes nothing
+    fn submit_attestation(
+        &mut self,
+        attestation: Attestation,
+        signature: [u8; 65],
+    ) -> Result<(), CoreError> {
+        let signer = self
+            .data::<Eligibility>()
+            .attestation_signer
+            .get_or_default()
+            .ok_or(EligibilityError::AttestationSignerNotSet)?;
+
+        if attestation.contract != Self::env().account_id()
+            || !signature::is_signed_by(&attestation, &signature, &signer)
+        {
+            return Err(EligibilityError::InvalidAttestationSignature.into());
+        }
+        if attestation.expires_at <= Self::env().block_timestamp() {
+            return Err(EligibilityError::AttestationExpired.into());
+        }
+
+        if self.get_attestation_expiry(attestation.account) >= Some(attestation.expires_at) {
+            return Ok(());
+        }
+
+        let epoch = self
+            .data::<Eligibility>()
+            .attestation_epoch
+            .get_or_default();
+        self.data::<Eligibility>()
+            .attestations
+            .insert(&(epoch, attestation.account), &attestation.expires_at);
+        self._emit_attestation_submitted_event(attestation);
+        Ok(())
+    }
+
+    // Set function
+    /// Add or remove account from the deny list
+    fn set_denied(&mut self, account: AccountId, denied: bool) -> Result<(), CoreError> {
+        if denied {
+            self.data::<Eligibility>().denied.insert(&account, &true);
+        } else {
+            self.data::<Eligibility>().denied.remove(&account);
+        }
+        self._emit_eligibility_changed_event(account, EligibilityList::Deny, denied);
+        Ok(())
+    }
+
+    /// Add or remove account from the allow list
+    fn set_allowed(&mut self, account: AccountId, allowed: bool) -> Result<(), CoreError> {
+        if allowed {
+            self.data::<Eligibility>().allowed.insert(&account, &true);
+        } else {
+            self.data::<Eligibility>().allowed.remove(&account);
+        }
+        self._emit_eligibility_changed_event(account, EligibilityList::Allow, allowed);
+        Ok(())
+    }
+
+    /// Enable or disable allow list mode
+    fn set_allow_list_enabled(&mut self, enabled: bool) -> Result<(), CoreError> {
+        let old_value = self.is_allow_list_enabled();
+        self.data::<Eligibility>().allow_list_enabled.set(&enabled);
+        self._emit_eligibility_setting_changed_event(
+            EligibilitySetting::AllowListEnabled,
+            old_value,
+            enabled,
+        );
+        Ok(())
+    }
+
+    /// Require a valid attestation to play
+    fn set_attestation_required(&mut self, required: bool) -> Result<(), CoreError> {
+        let old_value = self.is_attestation_required();
+        self.data::<Eligibility>()
+            .attestation_required
+            .set(&required);
+        self._emit_eligibility_setting_changed_event(
+            EligibilitySetting::AttestationRequired,
+            old_value,
+            required,
+        );
+        Ok(())
+    }
+
+    /// Set attestation signer, attestations of the previous signer are
+    /// revoked
+    fn set_attestation_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError> {
+        let old_signer = self.get_attestation_signer();
+        let eligibility = self.data::<Eligibility>();
+        let epoch = eligibility
+            .attestation_epoch
+            .get_or_default()
+            .checked_add(1)
+            .unwrap();
+        eligibility.attestation_signer.set(&signer);
+        eligibility.attestation_epoch.set(&epoch);
+        self._emit_attestation_signer_changed_event(old_signer, signer);
+        Ok(())
+    }
+
+    // Get Function
+    /// Check account may play
+    fn check_eligibility(&self, account: AccountId) -> Result<(), EligibilityError> {
+        let eligibility = self.data::<Eligibility>();
+
+        if eligibility.denied.get(&account).unwrap_or_default() {
+            return Err(EligibilityError::Denied);
+        }
+        if eligibility.allow_list_enabled.get_or_default()
+            && !eligibility.allowed.get(&account).unwrap_or_default()
+        {
+            return Err(EligibilityError::NotAllowListed);
+        }
+        if eligibility.attestation_required.get_or_default() {
+            match self.get_attestation_expiry(account) {
+                None => return Err(EligibilityError::AttestationRequired),
+                Some(expires_at) if expires_at <= Self::env().block_timestamp() => {
+                    return Err(EligibilityError::AttestationExpired)
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Is account on the deny list
+    fn is_denied(&self, account: AccountId) -> bool {
+        self.data::<Eligibility>()
+            .denied
+            .get(&account)
+            .unwrap_or_default()
+    }
+
+    /// Is account on the allow list
+    fn is_allowed(&self, account: AccountId) -> bool {
+        self.data::<Eligibility>()
+            .allowed
+            .get(&account)
+            .unwrap_or_default()
+    }
+
+    /// Is allow list enabled
+    fn is_allow_list_enabled(&self) -> bool {
+        self.data::<Eligibility>()
+            .allow_list_enabled
+            .get_or_default()
+    }
+
+    /// Is attestation required
+    fn is_attestation_required(&self) -> bool {
+        self.data::<Eligibility>()
+            .attestation_required
+            .get_or_default()
+    }
+
+    /// get attestation signer
+    fn get_attestation_signer(&self) -> Option<[u8; 33]> {
+        self.data::<Eligibility>()
+            .attestation_signer
+            .get_or_default()
+    }
+
+    /// get expiry of the account attestation by the current signer
+    fn get_attestation_expiry(&self, account: AccountId) -> Option<Timestamp> {
+        let eligibility = self.data::<Eligibility>();
+        eligibility
+            .attestations
+            .get(&(eligibility.attestation_epoch.get_or_default(), account))
+    }
+
+    // Events
+    /// Emit event when an account is added to or removed from a list
+    fn _emit_eligibility_changed_event(
+        &self,
+        _account: AccountId,
+        _list: EligibilityList,
+        _listed: bool,
+    ) {
+    }
+
+    /// Emit event when an attestation is stored
+    fn _emit_attestation_submitted_event(&self, _attestation: Attestation) {}
+
+    /// Emit event when an eligibility check is switched
+    fn _emit_eligibility_setting_changed_event(
+        &self,
+        _setting: EligibilitySetting,
+        _old_value: bool,
+        _new_value: bool,
+    ) {
+    }
+
+    /// Emit event when the attestation signer changes
+    fn _emit_attestation_signer_changed_event(
+        &self,
+        _old_signer: Option<[u8; 33]>,
+        _new_signer: Option<[u8; 33]>,
+    ) {
+    }
+}
@@ -0,0 +1,3 @@
+pub mod data;
+pub mod eligibility;
+pub use eligibility::*;
@@ -0,0 +1,46 @@
+use openbrush::traits::{AccountId, Timestamp};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct OwnerRecovery {
+    pub new_owner: AccountId,
+    pub started_at: Timestamp,
+    pub eta: Timestamp,
+}
+
+/// Bounds of the recovery delay the owner can set, one to thirty days
+pub const MIN_RECOVERY_DELAY: Timestamp = 86_400_000;
+pub const MAX_RECOVERY_DELAY: Timestamp = 30 * 86_400_000;
+/// Recovery delay until the owner sets one
+pub const DEFAULT_RECOVERY_DELAY: Timestamp = 7 * 86_400_000;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(GuardedOwnable);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct GuardedOwnable {
+    #[lazy]
+    pub pending_owner: Option<AccountId>,
+    #[lazy]
+    pub guardian: Option<AccountId>,
+    #[lazy]
+    pub recovery_delay: Timestamp,
+    #[lazy]
+    pub recovery: Option<OwnerRecovery>,
+}
+
+impl Default for GuardedOwnable {
+    fn default() -> Self {
+        Self {
+            pending_owner: Default::default(),
+            guardian: Default::default(),
+            recovery_delay: Default::default(),
+            recovery: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+pub use crate::{
+    impls::guarded_ownable::{
+        data,
+        data::{GuardedOwnable, *},
+        guarded_ownable,
+    },
+    traits::guarded_ownable::*,
+};
+use openbrush::{
+    contracts::{ownable::*, pausable::*},
+    traits::{AccountId, Storage, Timestamp},
+};
+
+pub trait GuardedOwnableTraitImpl:
+    Storage<GuardedOwnable>
+    + Storage<ownable::Data>
+    + Storage<pausable::Data>
+    + ownable::Internal
+    + pausable::Internal
+    + ownable::Ownable
+{
+    // Execute function
+    /// Propose a new owner, not while a recovery is pending
+    fn propose_owner(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+        if self.get_recovery().is_some() {
+            return Err(GuardedOwnableError::RecoveryPending);
+        }
+
+        self.data::<GuardedOwnable>()
+            .pending_owner
+            .set(&Some(new_owner));
+        self._emit_ownership_proposed_event(new_owner);
+        Ok(())
+    }
+
+    /// Accept ownership, not while a recovery is pending
+    fn accept_ownership(&mut self) -> Result<(), GuardedOwnableError> {
+        if self.get_recovery().is_some() {
+            return Err(GuardedOwnableError::RecoveryPending);
+        }
+
+        let caller = Self::env().caller();
+        if self.data::<GuardedOwnable>().pending_owner.get_or_default() != Some(caller) {
+            return Err(GuardedOwnableError::CallerIsNotPendingOwner);
+        }
+
+        self._move_ownership(caller)
+    }
+
+    /// Set or remove the guardian, not while a recovery is pending
+    fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<(), GuardedOwnableError> {
+        if self.get_recovery().is_some() {
+            return Err(GuardedOwnableError::RecoveryPending);
+        }
+
+        let old_guardian = self.data::<GuardedOwnable>().guardian.get_or_default();
+        self.data::<GuardedOwnable>().guardian.set(&guardian);
+        self._emit_guardian_changed_event(old_guardian, guardian);
+        Ok(())
+    }
+
+    /// Set recovery delay, between MIN_RECOVERY_DELAY and MAX_RECOVERY_DELAY
+    fn set_recovery_delay(&mut self, recovery_delay: Timestamp) -> Result<(), GuardedOwnableError> {
+        if recovery_delay < MIN_RECOVERY_DELAY {
+            return Err(GuardedOwnableError::RecoveryDelayTooShort);
+        }
+        if recovery_delay > MAX_RECOVERY_DELAY {
+            return Err(GuardedOwnableError::RecoveryDelayTooLong);
+        }
+
+        let old_delay = self.get_recovery_delay();
+        self.data::<GuardedOwnable>()
+            .recovery_delay
+            .set(&recovery_delay);
+        self._emit_recovery_delay_changed_event(old_delay, recovery_delay);
+        Ok(())
+    }
+
+    /// Pause the contract
+    fn guardian_pause(&mut self) -> Result<(), GuardedOwnableError> {
+        self._check_guardian()?;
+        if !pausable::Internal::_paused(self) {
+            pausable::Internal::_pause(self)?;
+        }
+        Ok(())
+    }
+
+    /// Start a delayed owner recovery
+    fn start_recovery(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+        self._check_guardian()?;
+
+        let now = Self::env().block_timestamp();
+        let recovery = OwnerRecovery {
+            new_owner,
+            started_at: now,
+            eta: now.saturating_add(self.get_recovery_delay()),
+        };
+        self.data::<GuardedOwnable>().recovery.set(&Some(recovery));
+        self._emit_recovery_started_event(recovery);
+        Ok(())
+    }
+
+    /// Cancel a started recovery, the owner it recovers from cannot
+    fn cancel_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+        self._check_guardian()?;
+
+        if let Some(recovery) = self.data::<GuardedOwnable>().recovery.get_or_default() {
+            self.data::<GuardedOwnable>().recovery.set(&None);
+            self._emit_recovery_cancelled_event(recovery);
+            Ok(())
+        } else {
+            Err(GuardedOwnableError::RecoveryNotStarted)
+        }
+    }
+
+    /// Complete a recovery once the delay has passed
+    fn complete_recovery(&mut self) -> Result<(), GuardedOwnableError> {
+        self._check_guardian()?;
+
+        let recovery = self
+            .data::<GuardedOwnable>()
+            .recovery
+            .get_or_default()
+            .ok_or(GuardedOwnableError::RecoveryNotStarted)?;
+
+        if Self::env().block_timestamp() < recovery.eta {
+            return Err(GuardedOwnableError::RecoveryNotReady);
+        }
+
+        self._move_ownership(recovery.new_owner)
+    }
+
+    // Get Function
+    /// get pending owner
+    fn get_pending_owner(&self) -> Option<AccountId> {
+        self.data::<GuardedOwnable>().pending_owner.get_or_default()
+    }
+
+    /// get guardian
+    fn get_guardian(&self) -> Option<AccountId> {
+        self.data::<GuardedOwnable>().guardian.get_or_default()
+    }
+
+    /// get recovery delay
+    fn get_recovery_delay(&self) -> Timestamp {
+        self.data::<GuardedOwnable>()
+            .recovery_delay
+            .get()
+            .unwrap_or(DEFAULT_RECOVERY_DELAY)
+    }
+
+    /// get started recovery
+    fn get_recovery(&self) -> Option<OwnerRecovery> {
+        self.data::<GuardedOwnable>().recovery.get_or_default()
+    }
+
+    /// Caller must be the guardian
+    fn _check_guardian(&self) -> Result<(), GuardedOwnableError> {
+        let guardian = self.data::<GuardedOwnable>().guardian.get_or_default();
+        if guardian.is_none() || guardian != Some(Self::env().caller()) {
+            return Err(GuardedOwnableError::CallerIsNotGuardian);
+        }
+        Ok(())
+    }
+
+    /// Transfer ownership and clear any pending proposal or recovery
+    fn _move_ownership(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError> {
+        let old_owner = Ownable::owner(self);
+
+        ownable::Internal::_transfer_ownership(self, Some(new_owner))?;
+        self.data::<GuardedOwnable>().pending_owner.set(&None);
+        self.data::<GuardedOwnable>().recovery.set(&None);
+
+        self._after_ownership_moved(old_owner, new_owner);
+        self._emit_ownership_moved_event(old_owner, new_owner);
+        Ok(())
+    }
+
+    /// Called after ownership moves, e.g. to hand over admin roles
+    fn _after_ownership_moved(&mut self, _old_owner: Option<AccountId>, _new_owner: AccountId) {}
+
+    // Events
+    /// Emit event when a new owner is proposed
+    fn _emit_ownership_proposed_event(&self, _pending_owner: AccountId) {}
+
+    /// Emit event when a proposal is accepted or a recovery completes
+    fn _emit_ownership_moved_event(&self, _old_owner: Option<AccountId>, _new_owner: AccountId) {}
+
+    /// Emit event when the guardian changes
+    fn _emit_guardian_changed_event(
+        &self,
+        _old_guardian: Option<AccountId>,
+        _new_guardian: Option<AccountId>,
+    ) {
+    }
+
+    /// Emit event when the recovery delay changes
+    fn _emit_recovery_delay_changed_event(&self, _old_delay: Timestamp, _new_delay: Timestamp) {}
+
+    /// Emit event when a recovery starts
+    fn _emit_recovery_started_event(&self, _recovery: OwnerRecovery) {}
+
+    /// Emit event when a recovery is cancelled
+    fn _emit_recovery_cancelled_event(&self, _recovery: OwnerRecovery) {}
+}
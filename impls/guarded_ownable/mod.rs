@@ -0,0 +1,3 @@
+pub mod data;
+pub mod guarded_ownable;
+pub use guarded_ownable::*;
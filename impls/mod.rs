@@ -1 +1,10 @@
-pub mod beta0_core;
\ No newline at end of file
+pub mod beta0_core;
+pub mod bonus;
+pub mod eligibility;
+pub mod guarded_ownable;
+pub mod promo;
+pub mod relay;
+pub mod responsible_gaming;
+pub mod session;
+pub mod signature;
+pub mod timelock;
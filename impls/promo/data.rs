@@ -0,0 +1,78 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+use crate::impls::signature::SignedPayload;
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Reward credited by a promo code
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum PromoReward {
+    /// BET tokens sent from the bet pool
+    BetTokens(Balance),
+    /// Bonus credits, as granted by `grant_bonus`
+    BonusCredits {
+        credits: Balance,
+        wagering_required: Balance,
+        bonus_expires_at: Timestamp,
+    },
+    /// Added to the multiplier of the next bet, 10000 is 1x
+    MultiplierBoost(u32),
+}
+
+/// How often a promo code may be redeemed
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum RedemptionLimit {
+    /// Times each account may redeem the code
+    PerAccount(u32),
+    /// Times the code may be redeemed by all accounts together
+    Global(u32),
+    /// Both limits at once
+    PerAccountAndGlobal { per_account: u32, global: u32 },
+}
+
+/// Promo code signed by the promo signer. Redemptions are counted by
+/// `code_id`, `contract` keeps a code from being redeemed on another
+/// deployment.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PromoCode {
+    pub code_id: u32,
+    pub contract: AccountId,
+    pub reward: PromoReward,
+    pub limit: RedemptionLimit,
+    pub expires_at: Timestamp,
+}
+
+impl SignedPayload for PromoCode {
+    const TYPE_TAG: &'static [u8] = b"PromoCode";
+}
+
+/// Largest multiplier boost a player can hold, 10000 is 1x
+pub const MAX_MULTIPLIER_BOOST: u32 = 10000;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Promo);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Promo {
+    #[lazy]
+    pub promo_signer: Option<[u8; 33]>,
+    pub redemptions: Mapping<u32, u32>,
+    pub account_redemptions: Mapping<(u32, AccountId), u32>,
+    pub multiplier_boosts: Mapping<AccountId, u32>,
+}
+
+impl Default for Promo {
+    fn default() -> Self {
+        Self {
+            promo_signer: Default::default(),
+            redemptions: Default::default(),
+            account_redemptions: Default::default(),
+            multiplier_boosts: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod data;
+pub mod promo;
+pub use promo::*;
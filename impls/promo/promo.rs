@@ -0,0 +1,167 @@
+pub use crate::{
+    impls::{
+        beta0_core::{BetA0CoreTraitImpl, PausableOperation},
+        bonus::BonusTraitImpl,
+        eligibility::EligibilityTraitImpl,
+        promo::{
+            data,
+            data::{Promo, *},
+            promo,
+        },
+        signature,
+    },
+    traits::{beta0_core::CoreError, promo::*},
+};
+use openbrush::traits::{AccountId, Storage, String};
+
+pub trait PromoTraitImpl: Storage<Promo> + BetA0CoreTraitImpl {
+    // Execute function
+    /// Credit the reward of a signed promo code to the caller
+    fn redeem_promo(&mut self, code: PromoCode, signature: [u8; 65]) -> Result<(), CoreError> {
+        // state contract
+        self._check_operation(PausableOperation::Rewards)?;
+
+        let player = Self::env().caller();
+        EligibilityTraitImpl::check_eligibility(self, player)?;
+
+        let signer = self
+            .data::<Promo>()
+            .promo_signer
+            .get_or_default()
+            .ok_or(CoreError::Custom(String::from("O::Promo Signer Not Set")))?;
+
+        if code.contract != Self::env().account_id()
+            || !signature::is_signed_by(&code, &signature, &signer)
+        {
+            return Err(CoreError::Custom(String::from(
+                "O::Invalid Promo Signature",
+            )));
+        }
+        if code.expires_at <= Self::env().block_timestamp() {
+            return Err(CoreError::Custom(String::from("O::Promo Expired")));
+        }
+
+        self._record_redemption(&code, player)?;
+
+        match code.reward {
+            PromoReward::BetTokens(amount) => {
+                if self._send_reward_tokens(player, amount)? < amount {
+                    return Err(CoreError::Custom(String::from("O::Not Enough Balance")));
+                }
+            }
+            PromoReward::BonusCredits {
+                credits,
+                wagering_required,
+                bonus_expires_at,
+            } => {
+                BonusTraitImpl::grant_bonus(
+                    self,
+                    player,
+                    credits,
+                    wagering_required,
+                    bonus_expires_at,
+                )?;
+            }
+            PromoReward::MultiplierBoost(boost) => {
+                let multiplier_boost = self
+                    .get_multiplier_boost(player)
+                    .checked_add(boost)
+                    .filter(|multiplier_boost| *multiplier_boost <= MAX_MULTIPLIER_BOOST)
+                    .ok_or(CoreError::Custom(String::from("O::Boost Above Maximum")))?;
+                self.data::<Promo>()
+                    .multiplier_boosts
+                    .insert(&player, &multiplier_boost);
+            }
+        }
+
+        self._emit_promo_redeemed_event(player, code.code_id, code.reward);
+        Ok(())
+    }
+
+    // Set function
+    /// Set promo signer
+    fn set_promo_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError> {
+        let old_signer = self.get_promo_signer();
+        self.data::<Promo>().promo_signer.set(&signer);
+        self._emit_promo_signer_changed_event(old_signer, signer);
+        Ok(())
+    }
+
+    // Get Function
+    /// get promo signer
+    fn get_promo_signer(&self) -> Option<[u8; 33]> {
+        self.data::<Promo>().promo_signer.get_or_default()
+    }
+
+    /// get redemptions of a promo code by all accounts
+    fn get_promo_redemptions(&self, code_id: u32) -> u32 {
+        self.data::<Promo>()
+            .redemptions
+            .get(&code_id)
+            .unwrap_or_default()
+    }
+
+    /// get redemptions of a promo code by account
+    fn get_account_promo_redemptions(&self, code_id: u32, account: AccountId) -> u32 {
+        self.data::<Promo>()
+            .account_redemptions
+            .get(&(code_id, account))
+            .unwrap_or_default()
+    }
+
+    /// get multiplier boost waiting for the next bet of player
+    fn get_multiplier_boost(&self, player: AccountId) -> u32 {
+        self.data::<Promo>()
+            .multiplier_boosts
+            .get(&player)
+            .unwrap_or_default()
+    }
+
+    /// Count a redemption, fails once the code reached its limit
+    fn _record_redemption(&mut self, code: &PromoCode, player: AccountId) -> Result<(), CoreError> {
+        let redemptions = self.get_promo_redemptions(code.code_id);
+        let account_redemptions = self.get_account_promo_redemptions(code.code_id, player);
+
+        let limit_reached = match code.limit {
+            RedemptionLimit::PerAccount(limit) => account_redemptions >= limit,
+            RedemptionLimit::Global(limit) => redemptions >= limit,
+            RedemptionLimit::PerAccountAndGlobal {
+                per_account,
+                global,
+            } => account_redemptions >= per_account || redemptions >= global,
+        };
+        if limit_reached {
+            return Err(CoreError::Custom(String::from("O::Promo Limit Reached")));
+        }
+
+        self.data::<Promo>()
+            .redemptions
+            .insert(&code.code_id, &redemptions.checked_add(1).unwrap());
+        self.data::<Promo>().account_redemptions.insert(
+            &(code.code_id, player),
+            &account_redemptions.checked_add(1).unwrap(),
+        );
+        Ok(())
+    }
+
+    /// Take the multiplier boost of player for the bet being placed
+    fn _take_multiplier_boost(&mut self, player: AccountId) -> u32 {
+        let multiplier_boost = self.get_multiplier_boost(player);
+        if multiplier_boost > 0 {
+            self.data::<Promo>().multiplier_boosts.remove(&player);
+        }
+        multiplier_boost
+    }
+
+    // Events
+    /// Emit event when a promo code is redeemed
+    fn _emit_promo_redeemed_event(&self, _player: AccountId, _code_id: u32, _reward: PromoReward) {}
+
+    /// Emit event when the promo signer changes
+    fn _emit_promo_signer_changed_event(
+        &self,
+        _old_signer: Option<[u8; 33]>,
+        _new_signer: Option<[u8; 33]>,
+    ) {
+    }
+}
@@ -0,0 +1,49 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+use crate::impls::signature::SignedPayload;
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Bet signed off-chain with the ECDSA key of `player` for a relayer to
+/// submit with `play_signed`. Stakes are native currency, so `amount` is
+/// taken from the player balance, `relayer_fee` of it is credited to the
+/// relayer and the rest is the stake.
+///
+/// The intent is not bound to a relayer. Anyone holding it can submit it
+/// and be credited `relayer_fee`, which is intended: the player signs what
+/// they bet and what they pay, not who delivers it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct BetIntent {
+    pub player: AccountId,
+    pub contract: AccountId,
+    pub bet_number: u32,
+    pub is_over: u8,
+    pub min_multiplier: u32,
+    pub amount: Balance,
+    pub relayer_fee: Balance,
+    pub nonce: u64,
+    pub deadline: Timestamp,
+}
+
+impl SignedPayload for BetIntent {
+    const TYPE_TAG: &'static [u8] = b"BetIntent";
+}
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Relay);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Relay {
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+impl Default for Relay {
+    fn default() -> Self {
+        Self {
+            nonces: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+pub use crate::{
+    impls::{
+        relay::{
+            data,
+            data::{Relay, *},
+            relay,
+        },
+        signature,
+    },
+    traits::{beta0_core::CoreError, relay::*},
+};
+use openbrush::traits::{AccountId, Storage, String};
+
+pub trait RelayTraitImpl: Storage<Relay> {
+    // Execute function
+    /// Use up the next nonce of the caller
+    fn invalidate_nonce(&mut self) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let nonce = self.get_nonce(player);
+        self._use_nonce(player, nonce);
+        self._emit_nonce_invalidated_event(player, nonce);
+        Ok(())
+    }
+
+    // Get Function
+    /// get the nonce the next signed bet of player must use
+    fn get_nonce(&self, player: AccountId) -> u64 {
+        self.data::<Relay>().nonces.get(&player).unwrap_or_default()
+    }
+
+    /// Check a signed bet and use up its nonce
+    fn _use_bet_intent(
+        &mut self,
+        intent: &BetIntent,
+        signature: &[u8; 65],
+    ) -> Result<(), CoreError> {
+        let signer = signature::recover_signer(intent, signature)
+            .ok_or(CoreError::Custom(String::from("O::Invalid Bet Signature")))?;
+
+        if intent.contract != Self::env().account_id()
+            || signature::account_id_of(&signer) != intent.player
+        {
+            return Err(CoreError::Custom(String::from("O::Invalid Bet Signature")));
+        }
+        if intent.deadline <= Self::env().block_timestamp() {
+            return Err(CoreError::Custom(String::from("O::Bet Expired")));
+        }
+        if intent.nonce != self.get_nonce(intent.player) {
+            return Err(CoreError::Custom(String::from("O::Invalid Nonce")));
+        }
+
+        self._use_nonce(intent.player, intent.nonce);
+        Ok(())
+    }
+
+    fn _use_nonce(&mut self, player: AccountId, nonce: u64) {
+        self.data::<Relay>()
+            .nonces
+            .insert(&player, &nonce.checked_add(1).unwrap());
+    }
+
+    // Events
+    /// Emit event when a player invalidates their next nonce
+    fn _emit_nonce_invalidated_event(&self, _player: AccountId, _nonce: u64) {}
+}
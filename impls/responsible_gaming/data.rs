@@ -0,0 +1,127 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+pub const DAY: Timestamp = 86_400_000;
+pub const WEEK: Timestamp = 7 * DAY;
+pub const MONTH: Timestamp = 30 * DAY;
+
+/// Default wait before a raised limit applies
+pub const DEFAULT_LIMIT_INCREASE_DELAY: Timestamp = DAY;
+/// Shortest wait before a raised limit applies
+pub const MIN_LIMIT_INCREASE_DELAY: Timestamp = DAY;
+
+/// Player chosen limits, `None` means no limit
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PlayerLimits {
+    pub daily_loss: Option<Balance>,
+    pub weekly_loss: Option<Balance>,
+    pub monthly_loss: Option<Balance>,
+    pub daily_wager: Option<Balance>,
+    pub weekly_wager: Option<Balance>,
+    pub monthly_wager: Option<Balance>,
+    /// Minimum time between two bets
+    pub session_cooldown: Timestamp,
+}
+
+/// Raised limits waiting for the increase delay
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PendingLimits {
+    pub limits: PlayerLimits,
+    pub effective_at: Timestamp,
+}
+
+/// Wagered and returned amounts within one period, loss is wagered - returned
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PeriodActivity {
+    pub started_at: Timestamp,
+    pub wagered: Balance,
+    pub returned: Balance,
+}
+
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PlayerActivity {
+    pub daily: PeriodActivity,
+    pub weekly: PeriodActivity,
+    pub monthly: PeriodActivity,
+    pub last_bet_at: Option<Timestamp>,
+}
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(ResponsibleGaming);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct ResponsibleGaming {
+    pub limits: Mapping<AccountId, PlayerLimits>,
+    pub pending_limits: Mapping<AccountId, PendingLimits>,
+    pub activity: Mapping<AccountId, PlayerActivity>,
+    pub self_excluded_until: Mapping<AccountId, Timestamp>,
+    #[lazy]
+    pub limit_increase_delay: Timestamp,
+}
+
+impl Default for ResponsibleGaming {
+    fn default() -> Self {
+        Self {
+            limits: Default::default(),
+            pending_limits: Default::default(),
+            activity: Default::default(),
+            self_excluded_until: Default::default(),
+            limit_increase_delay: Default::default(),
+        }
+    }
+}
+
+impl PlayerLimits {
+    /// Field by field the stricter of both limits
+    pub fn stricter(&self, other: &PlayerLimits) -> PlayerLimits {
+        PlayerLimits {
+            daily_loss: stricter_limit(self.daily_loss, other.daily_loss),
+            weekly_loss: stricter_limit(self.weekly_loss, other.weekly_loss),
+            monthly_loss: stricter_limit(self.monthly_loss, other.monthly_loss),
+            daily_wager: stricter_limit(self.daily_wager, other.daily_wager),
+            weekly_wager: stricter_limit(self.weekly_wager, other.weekly_wager),
+            monthly_wager: stricter_limit(self.monthly_wager, other.monthly_wager),
+            session_cooldown: self.session_cooldown.max(other.session_cooldown),
+        }
+    }
+}
+
+impl PeriodActivity {
+    /// Amount lost in the period so far
+    pub fn loss(&self) -> Balance {
+        self.wagered.saturating_sub(self.returned)
+    }
+
+    /// Start a new period once `length` has passed
+    pub fn roll(&mut self, now: Timestamp, length: Timestamp) {
+        if now >= self.started_at.saturating_add(length) {
+            *self = PeriodActivity {
+                started_at: now,
+                ..Default::default()
+            };
+        }
+    }
+}
+
+fn stricter_limit(a: Option<Balance>, b: Option<Balance>) -> Option<Balance> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (Some(a), None) => Some(a),
+        (None, b) => b,
+    }
+}
@@ -0,0 +1,3 @@
+pub mod data;
+pub mod responsible_gaming;
+pub use responsible_gaming::*;
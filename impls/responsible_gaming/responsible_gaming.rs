@@ -0,0 +1,225 @@
+pub use crate::{
+    impls::responsible_gaming::{
+        data,
+        data::{ResponsibleGaming, *},
+        responsible_gaming,
+    },
+    traits::{beta0_core::CoreError, responsible_gaming::*},
+};
+use openbrush::traits::{AccountId, Balance, Storage, String, Timestamp};
+
+pub trait ResponsibleGamingTraitImpl: Storage<ResponsibleGaming> {
+    // Execute function
+    /// Set caller limits
+    fn set_limits(&mut self, limits: PlayerLimits) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let current = self.get_limits(player);
+
+        // Lowering applies now, raising waits for the increase delay
+        let applied = current.stricter(&limits);
+        self.data::<ResponsibleGaming>()
+            .limits
+            .insert(&player, &applied);
+
+        if applied != limits {
+            let pending = PendingLimits {
+                limits,
+                effective_at: Self::env()
+                    .block_timestamp()
+                    .saturating_add(self.get_limit_increase_delay()),
+            };
+            self.data::<ResponsibleGaming>()
+                .pending_limits
+                .insert(&player, &pending);
+            self._emit_limits_increase_queued_event(player, pending);
+        } else {
+            self.data::<ResponsibleGaming>()
+                .pending_limits
+                .remove(&player);
+        }
+
+        self._emit_limits_changed_event(player, applied);
+        Ok(())
+    }
+
+    /// Exclude caller from play, u64::MAX excludes for good
+    fn self_exclude(&mut self, duration: Timestamp) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let until = Self::env().block_timestamp().saturating_add(duration);
+
+        let current = self
+            .data::<ResponsibleGaming>()
+            .self_excluded_until
+            .get(&player)
+            .unwrap_or_default();
+        if until <= current {
+            return Err(CoreError::Custom(String::from(
+                "O::Exclusion Cannot Be Shortened",
+            )));
+        }
+
+        self.data::<ResponsibleGaming>()
+            .self_excluded_until
+            .insert(&player, &until);
+        self._emit_self_excluded_event(player, until);
+        Ok(())
+    }
+
+    // Set function
+    /// Set limit increase delay, at least MIN_LIMIT_INCREASE_DELAY
+    fn set_limit_increase_delay(
+        &mut self,
+        limit_increase_delay: Timestamp,
+    ) -> Result<(), CoreError> {
+        if limit_increase_delay < MIN_LIMIT_INCREASE_DELAY {
+            return Err(CoreError::Custom(String::from("O::Delay Too Short")));
+        }
+
+        let old_delay = self.get_limit_increase_delay();
+        self.data::<ResponsibleGaming>()
+            .limit_increase_delay
+            .set(&limit_increase_delay);
+        self._emit_limit_increase_delay_changed_event(old_delay, limit_increase_delay);
+        Ok(())
+    }
+
+    // Get Function
+    /// get limits in force for player
+    fn get_limits(&self, player: AccountId) -> PlayerLimits {
+        let rg = self.data::<ResponsibleGaming>();
+        if let Some(pending) = rg.pending_limits.get(&player) {
+            if Self::env().block_timestamp() >= pending.effective_at {
+                return pending.limits;
+            }
+        }
+        rg.limits.get(&player).unwrap_or_default()
+    }
+
+    /// get raised limits waiting for the increase delay
+    fn get_pending_limits(&self, player: AccountId) -> Option<PendingLimits> {
+        self.data::<ResponsibleGaming>()
+            .pending_limits
+            .get(&player)
+            .filter(|pending| Self::env().block_timestamp() < pending.effective_at)
+    }
+
+    /// get player activity in the current periods
+    fn get_activity(&self, player: AccountId) -> PlayerActivity {
+        let mut activity = self
+            .data::<ResponsibleGaming>()
+            .activity
+            .get(&player)
+            .unwrap_or_default();
+
+        let now = Self::env().block_timestamp();
+        activity.daily.roll(now, DAY);
+        activity.weekly.roll(now, WEEK);
+        activity.monthly.roll(now, MONTH);
+        activity
+    }
+
+    /// get end of player self exclusion
+    fn get_self_excluded_until(&self, player: AccountId) -> Option<Timestamp> {
+        self.data::<ResponsibleGaming>()
+            .self_excluded_until
+            .get(&player)
+            .filter(|until| Self::env().block_timestamp() < *until)
+    }
+
+    /// get limit increase delay
+    fn get_limit_increase_delay(&self) -> Timestamp {
+        self.data::<ResponsibleGaming>()
+            .limit_increase_delay
+            .get()
+            .unwrap_or(DEFAULT_LIMIT_INCREASE_DELAY)
+    }
+
+    /// Bet must respect the player's exclusion, cooldown, wager and loss limits
+    fn _check_player_limits(
+        &self,
+        player: AccountId,
+        bet_amount: Balance,
+    ) -> Result<(), CoreError> {
+        if self.get_self_excluded_until(player).is_some() {
+            return Err(CoreError::Custom(String::from("O::Self Excluded")));
+        }
+
+        let limits = self.get_limits(player);
+        let activity = self.get_activity(player);
+
+        if let Some(last_bet_at) = activity.last_bet_at {
+            if Self::env().block_timestamp() < last_bet_at.saturating_add(limits.session_cooldown) {
+                return Err(CoreError::Custom(String::from("O::Session Cooldown")));
+            }
+        }
+
+        for (period, wager_limit, loss_limit) in [
+            (activity.daily, limits.daily_wager, limits.daily_loss),
+            (activity.weekly, limits.weekly_wager, limits.weekly_loss),
+            (activity.monthly, limits.monthly_wager, limits.monthly_loss),
+        ] {
+            if let Some(wager_limit) = wager_limit {
+                if period.wagered.saturating_add(bet_amount) > wager_limit {
+                    return Err(CoreError::Custom(String::from("O::Wager Limit Reached")));
+                }
+            }
+            // The whole stake counts as a possible loss
+            if let Some(loss_limit) = loss_limit {
+                if period.loss().saturating_add(bet_amount) > loss_limit {
+                    return Err(CoreError::Custom(String::from("O::Loss Limit Reached")));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add a placed bet to the player's activity
+    fn _record_wager(&mut self, player: AccountId, bet_amount: Balance) {
+        let mut activity = self.get_activity(player);
+        for period in [
+            &mut activity.daily,
+            &mut activity.weekly,
+            &mut activity.monthly,
+        ] {
+            period.wagered = period.wagered.saturating_add(bet_amount);
+        }
+        activity.last_bet_at = Some(Self::env().block_timestamp());
+        self.data::<ResponsibleGaming>()
+            .activity
+            .insert(&player, &activity);
+    }
+
+    /// Add a payout to the player's activity
+    fn _record_return(&mut self, player: AccountId, amount: Balance) {
+        let mut activity = self.get_activity(player);
+        for period in [
+            &mut activity.daily,
+            &mut activity.weekly,
+            &mut activity.monthly,
+        ] {
+            period.returned = period.returned.saturating_add(amount);
+        }
+        self.data::<ResponsibleGaming>()
+            .activity
+            .insert(&player, &activity);
+    }
+
+    // Events
+    /// Emit event when limits in force change
+    fn _emit_limits_changed_event(&self, _player: AccountId, _limits: PlayerLimits) {}
+
+    /// Emit event when raised limits are queued
+    fn _emit_limits_increase_queued_event(&self, _player: AccountId, _pending: PendingLimits) {}
+
+    /// Emit event when a player excludes themselves
+    fn _emit_self_excluded_event(&self, _player: AccountId, _until: Timestamp) {}
+
+    /// Emit event when the limit increase delay changes
+    fn _emit_limit_increase_delay_changed_event(
+        &self,
+        _old_delay: Timestamp,
+        _new_delay: Timestamp,
+    ) {
+    }
+}
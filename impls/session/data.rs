@@ -0,0 +1,42 @@
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Secondary account allowed to bet for `player` from the player balance
+/// until `expires_at`, for at most `max_total_stake` in total and only the
+/// allowed bet kinds. Winnings always go to `player`. A registration is
+/// pending until the key accepts it, so nobody can claim a key they do not
+/// control.
+#[derive(
+    Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Default, scale::Encode, scale::Decode,
+)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct SessionKey {
+    pub player: AccountId,
+    pub expires_at: Timestamp,
+    pub max_total_stake: Balance,
+    pub staked: Balance,
+    pub allow_over: bool,
+    pub allow_under: bool,
+}
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Session);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Session {
+    pub keys: Mapping<AccountId, SessionKey>,
+    /// Registrations waiting for the key to accept, by (key, player)
+    pub pending_keys: Mapping<(AccountId, AccountId), SessionKey>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            keys: Default::default(),
+            pending_keys: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod data;
+pub mod session;
+pub use session::*;
@@ -0,0 +1,157 @@
+pub use crate::{
+    impls::session::{
+        data,
+        data::{Session, *},
+        session,
+    },
+    traits::{beta0_core::CoreError, session::*},
+};
+use openbrush::traits::{AccountId, Balance, Storage, String, Timestamp};
+
+pub trait SessionTraitImpl: Storage<Session> {
+    // Execute function
+    /// Propose key to bet for the caller, the key takes it up with
+    /// accept_session_key. Replaces a pending proposal of the caller for
+    /// the same key
+    fn register_session_key(
+        &mut self,
+        key: AccountId,
+        expires_at: Timestamp,
+        max_total_stake: Balance,
+        allow_over: bool,
+        allow_under: bool,
+    ) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        if key == player
+            || expires_at <= Self::env().block_timestamp()
+            || (!allow_over && !allow_under)
+        {
+            return Err(CoreError::Custom(String::from("O::Invalid Input")));
+        }
+        self._check_session_key_free(key, player)?;
+
+        let session_key = SessionKey {
+            player,
+            expires_at,
+            max_total_stake,
+            staked: 0,
+            allow_over,
+            allow_under,
+        };
+        self.data::<Session>()
+            .pending_keys
+            .insert(&(key, player), &session_key);
+        self._emit_session_key_proposed_event(key, session_key);
+        Ok(())
+    }
+
+    /// Accept as the key the registration proposed by player, replaces the
+    /// terms of a registration by the same player
+    fn accept_session_key(&mut self, player: AccountId) -> Result<(), CoreError> {
+        let key = Self::env().caller();
+        let session_key = self
+            .get_pending_session_key(key, player)
+            .ok_or(CoreError::Custom(String::from("O::Session Key Not Exist")))?;
+        if session_key.expires_at <= Self::env().block_timestamp() {
+            return Err(CoreError::Custom(String::from("O::Session Key Expired")));
+        }
+        self._check_session_key_free(key, player)?;
+
+        let session_data = self.data::<Session>();
+        session_data.pending_keys.remove(&(key, player));
+        session_data.keys.insert(&key, &session_key);
+        self._emit_session_key_registered_event(key, session_key);
+        Ok(())
+    }
+
+    /// Revoke a session key of the caller, pending or accepted
+    fn revoke_session_key(&mut self, key: AccountId) -> Result<(), CoreError> {
+        let player = Self::env().caller();
+        let pending = self.get_pending_session_key(key, player).is_some();
+        let registered = self
+            .get_session_key(key)
+            .map_or(false, |session_key| session_key.player == player);
+        if !pending && !registered {
+            return Err(CoreError::Custom(String::from("O::Session Key Not Exist")));
+        }
+
+        let session_data = self.data::<Session>();
+        session_data.pending_keys.remove(&(key, player));
+        if registered {
+            session_data.keys.remove(&key);
+        }
+        self._emit_session_key_revoked_event(player, key);
+        Ok(())
+    }
+
+    // Get Function
+    /// get the terms of a session key
+    fn get_session_key(&self, key: AccountId) -> Option<SessionKey> {
+        self.data::<Session>().keys.get(&key)
+    }
+
+    /// get the terms player proposed for a session key
+    fn get_pending_session_key(&self, key: AccountId, player: AccountId) -> Option<SessionKey> {
+        self.data::<Session>().pending_keys.get(&(key, player))
+    }
+
+    /// A key registered for another player is in use until it expires
+    fn _check_session_key_free(&self, key: AccountId, player: AccountId) -> Result<(), CoreError> {
+        match self.get_session_key(key) {
+            Some(session_key)
+                if session_key.player != player
+                    && session_key.expires_at > Self::env().block_timestamp() =>
+            {
+                Err(CoreError::Custom(String::from("O::Session Key In Use")))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check a bet of the session key against its terms and count its
+    /// stake, returns the player it bets for
+    fn _spend_session_stake(
+        &mut self,
+        key: AccountId,
+        is_over: u8,
+        amount: Balance,
+    ) -> Result<AccountId, CoreError> {
+        let mut session_key = self
+            .get_session_key(key)
+            .ok_or(CoreError::Custom(String::from("O::Session Key Not Exist")))?;
+
+        if session_key.expires_at <= Self::env().block_timestamp() {
+            return Err(CoreError::Custom(String::from("O::Session Key Expired")));
+        }
+        let allowed = if is_over == 1 {
+            session_key.allow_over
+        } else {
+            session_key.allow_under
+        };
+        if !allowed {
+            return Err(CoreError::Custom(String::from(
+                "O::Bet Kind Not Allowed For Session Key",
+            )));
+        }
+
+        session_key.staked = session_key
+            .staked
+            .checked_add(amount)
+            .filter(|staked| *staked <= session_key.max_total_stake)
+            .ok_or(CoreError::Custom(String::from(
+                "O::Session Key Stake Limit Reached",
+            )))?;
+        self.data::<Session>().keys.insert(&key, &session_key);
+        Ok(session_key.player)
+    }
+
+    // Events
+    /// Emit event when a player proposes a session key
+    fn _emit_session_key_proposed_event(&self, _key: AccountId, _session_key: SessionKey) {}
+
+    /// Emit event when a key accepts a registration
+    fn _emit_session_key_registered_event(&self, _key: AccountId, _session_key: SessionKey) {}
+
+    /// Emit event when a player revokes a session key
+    fn _emit_session_key_revoked_event(&self, _player: AccountId, _key: AccountId) {}
+}
@@ -0,0 +1,47 @@
+use ink::env::hash::{Blake2x256, HashOutput};
+use openbrush::traits::AccountId;
+
+/// Prefix of every message signed for these contracts
+pub const SIGNING_DOMAIN: &[u8] = b"bet_a0";
+
+/// Payload signed off-chain. The signed message is the SCALE encoded
+/// `(SIGNING_DOMAIN, TYPE_TAG, payload)`, so a signature for one payload
+/// type is never accepted as another.
+pub trait SignedPayload: scale::Encode {
+    const TYPE_TAG: &'static [u8];
+}
+
+/// Hash that is signed for the payload
+pub fn signing_hash<T: SignedPayload>(payload: &T) -> [u8; 32] {
+    let mut message_hash = <Blake2x256 as HashOutput>::Type::default();
+    ink::env::hash_encoded::<Blake2x256, _>(
+        &(SIGNING_DOMAIN, T::TYPE_TAG, payload),
+        &mut message_hash,
+    );
+    message_hash
+}
+
+/// Recover the compressed ECDSA public key that signed the payload
+pub fn recover_signer<T: SignedPayload>(payload: &T, signature: &[u8; 65]) -> Option<[u8; 33]> {
+    let message_hash = signing_hash(payload);
+
+    let mut signer = [0u8; 33];
+    ink::env::ecdsa_recover(signature, &message_hash, &mut signer).ok()?;
+    Some(signer)
+}
+
+/// Check the payload was signed by `signer`
+pub fn is_signed_by<T: SignedPayload>(
+    payload: &T,
+    signature: &[u8; 65],
+    signer: &[u8; 33],
+) -> bool {
+    recover_signer(payload, signature).as_ref() == Some(signer)
+}
+
+/// Account of an ECDSA key, the blake2 hash of the compressed public key
+pub fn account_id_of(signer: &[u8; 33]) -> AccountId {
+    let mut account_id = <Blake2x256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Blake2x256>(signer, &mut account_id);
+    AccountId::from(account_id)
+}
@@ -0,0 +1,68 @@
+use ink::prelude::vec::Vec;
+use ink::storage::Mapping;
+use openbrush::traits::{AccountId, Timestamp};
+
+use crate::impls::beta0_core::data::{GameConfigUpdate, RevenueShare, TiePolicy};
+
+#[cfg(feature = "std")]
+use ink::storage::traits::StorageLayout;
+
+/// Parameter changes that must wait for the timelock delay
+#[derive(Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub enum TimelockAction {
+    SetRates {
+        over_rates: Vec<u32>,
+        under_rates: Vec<u32>,
+    },
+    SetTokenRatio(u32),
+    SetMaxBetRatio(u32),
+    SetRewardPool(AccountId),
+    SetGeneralPool(AccountId),
+    SetBetPool(AccountId),
+    SetTiePolicy(TiePolicy),
+    SetRevenueRatio(u32),
+    SetRevenueSplit(Vec<RevenueShare>),
+    UpdateConfig(GameConfigUpdate),
+    SetTimelockDelay(Timestamp),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(StorageLayout, scale_info::TypeInfo))]
+pub struct PendingChange {
+    pub id: u64,
+    pub action: TimelockAction,
+    pub queued_at: Timestamp,
+    pub eta: Timestamp,
+}
+
+/// Bounds of the timelock delay, one hour to thirty days
+pub const MIN_TIMELOCK_DELAY: Timestamp = 3_600_000;
+pub const MAX_TIMELOCK_DELAY: Timestamp = 30 * 86_400_000;
+/// Delay set at deployment
+pub const DEFAULT_TIMELOCK_DELAY: Timestamp = 86_400_000;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Timelock);
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct Timelock {
+    #[lazy]
+    pub delay: Timestamp,
+    #[lazy]
+    pub next_change_id: u64,
+    pub pending_changes: Mapping<u64, PendingChange>,
+    #[lazy]
+    pub pending_change_ids: Vec<u64>,
+}
+
+impl Default for Timelock {
+    fn default() -> Self {
+        Self {
+            delay: Default::default(),
+            next_change_id: Default::default(),
+            pending_changes: Default::default(),
+            pending_change_ids: Default::default(),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+pub use crate::{
+    impls::{
+        beta0_core::BetA0CoreTraitImpl,
+        timelock::{
+            data,
+            data::{Timelock, *},
+            timelock,
+        },
+    },
+    traits::{beta0_core::CoreError, timelock::*},
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{Storage, String, Timestamp};
+
+pub trait TimelockTraitImpl: Storage<Timelock> + BetA0CoreTraitImpl {
+    // Execute function
+    /// Queue a parameter change
+    fn queue_change(&mut self, action: TimelockAction, eta: Timestamp) -> Result<u64, CoreError> {
+        if let TimelockAction::SetTimelockDelay(delay) = action {
+            Self::_check_timelock_delay(delay)?;
+        }
+
+        let now = Self::env().block_timestamp();
+        if eta < now.saturating_add(self.get_timelock_delay()) {
+            return Err(CoreError::Custom(String::from("O::Eta Before Delay")));
+        }
+
+        let timelock = self.data::<Timelock>();
+        let id = timelock.next_change_id.get_or_default();
+        timelock.next_change_id.set(&id.checked_add(1).unwrap());
+
+        let change = PendingChange {
+            id,
+            action,
+            queued_at: now,
+            eta,
+        };
+        timelock.pending_changes.insert(&id, &change);
+        let mut pending_change_ids = timelock.pending_change_ids.get_or_default();
+        pending_change_ids.push(id);
+        timelock.pending_change_ids.set(&pending_change_ids);
+
+        self._emit_change_queued_event(change);
+        Ok(id)
+    }
+
+    /// Apply a queued change
+    fn execute_change(&mut self, id: u64) -> Result<(), CoreError> {
+        let change = self._take_pending_change(id)?;
+
+        if Self::env().block_timestamp() < change.eta {
+            return Err(CoreError::Custom(String::from("O::Timelock Not Expired")));
+        }
+
+        match change.action.clone() {
+            TimelockAction::SetRates {
+                over_rates,
+                under_rates,
+            } => self._set_rates(over_rates, under_rates)?,
+            TimelockAction::SetTokenRatio(token_ratio) => self._set_token_ratio(token_ratio)?,
+            TimelockAction::SetMaxBetRatio(max_bet_ratio) => {
+                self._set_max_bet_ratio(max_bet_ratio)?
+            }
+            TimelockAction::SetRewardPool(reward_pool) => self._set_reward_pool(reward_pool)?,
+            TimelockAction::SetGeneralPool(general_pool) => self._set_general_pool(general_pool)?,
+            TimelockAction::SetBetPool(bet_pool) => self._set_bet_pool(bet_pool)?,
+            TimelockAction::SetTiePolicy(tie_policy) => self._set_tie_policy(tie_policy)?,
+            TimelockAction::SetRevenueRatio(revenue_ratio) => {
+                self._set_revenue_ratio(revenue_ratio)?
+            }
+            TimelockAction::SetRevenueSplit(revenue_split) => {
+                self._set_revenue_split(revenue_split)?
+            }
+            TimelockAction::UpdateConfig(update) => self._apply_config_update(update)?,
+            TimelockAction::SetTimelockDelay(delay) => self._set_timelock_delay(delay)?,
+        }
+        self._validate_config()?;
+
+        self._emit_change_executed_event(change);
+        Ok(())
+    }
+
+    /// Drop a queued change
+    fn cancel_change(&mut self, id: u64) -> Result<(), CoreError> {
+        let change = self._take_pending_change(id)?;
+        self._emit_change_cancelled_event(change);
+        Ok(())
+    }
+
+    // Get Function
+    /// get timelock delay, DEFAULT_TIMELOCK_DELAY until one is set
+    fn get_timelock_delay(&self) -> Timestamp {
+        self.data::<Timelock>()
+            .delay
+            .get()
+            .unwrap_or(DEFAULT_TIMELOCK_DELAY)
+    }
+
+    /// get queued change
+    fn get_pending_change(&self, id: u64) -> Option<PendingChange> {
+        self.data::<Timelock>().pending_changes.get(&id)
+    }
+
+    /// get all queued changes
+    fn get_pending_changes(&self) -> Vec<PendingChange> {
+        let timelock = self.data::<Timelock>();
+        timelock
+            .pending_change_ids
+            .get_or_default()
+            .iter()
+            .filter_map(|id| timelock.pending_changes.get(id))
+            .collect()
+    }
+
+    /// Set the delay within its bounds
+    fn _set_timelock_delay(&mut self, delay: Timestamp) -> Result<(), CoreError> {
+        Self::_check_timelock_delay(delay)?;
+        self.data::<Timelock>().delay.set(&delay);
+        Ok(())
+    }
+
+    /// Delay must be between MIN_TIMELOCK_DELAY and MAX_TIMELOCK_DELAY
+    fn _check_timelock_delay(delay: Timestamp) -> Result<(), CoreError> {
+        if !(MIN_TIMELOCK_DELAY..=MAX_TIMELOCK_DELAY).contains(&delay) {
+            return Err(CoreError::Custom(String::from("O::Invalid Timelock Delay")));
+        }
+        Ok(())
+    }
+
+    /// Remove a queued change from the queue and return it
+    fn _take_pending_change(&mut self, id: u64) -> Result<PendingChange, CoreError> {
+        let timelock = self.data::<Timelock>();
+        let change = timelock
+            .pending_changes
+            .get(&id)
+            .ok_or(CoreError::Custom(String::from("O::Change Not Exist")))?;
+
+        timelock.pending_changes.remove(&id);
+        let mut pending_change_ids = timelock.pending_change_ids.get_or_default();
+        pending_change_ids.retain(|pending_id| *pending_id != id);
+        timelock.pending_change_ids.set(&pending_change_ids);
+        Ok(change)
+    }
+
+    // Events
+    /// Emit event when a change is queued
+    fn _emit_change_queued_event(&self, _change: PendingChange) {}
+
+    /// Emit event when a change is applied
+    fn _emit_change_executed_event(&self, _change: PendingChange) {}
+
+    /// Emit event when a change is cancelled
+    fn _emit_change_cancelled_event(&self, _change: PendingChange) {}
+}
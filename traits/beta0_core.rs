@@ -1,11 +1,21 @@
 use ink::prelude::vec::Vec;
 use openbrush::{
-    contracts::traits::{ownable::*, pausable::*, psp22::*},
+    contracts::{
+        access_control::*,
+        traits::{ownable::*, pausable::*, psp22::*},
+    },
     modifiers,
-    traits::{AccountId, Balance, String},
+    traits::{AccountId, Balance, BlockNumber, String, Timestamp},
 };
 
-use crate::impls::beta0_core::BetInformation;
+use crate::{
+    impls::beta0_core::{
+        BetInformation, BetQuote, ConfigParam, GameConfig, GameConfigUpdate, PausableOperation,
+        PayoutEntry, PlayerBetRate, RateLimits, RevenueShare, TiePolicy, CONFIGURATOR, FINALIZER,
+        PAUSER, TREASURER,
+    },
+    traits::eligibility::EligibilityError,
+};
 
 #[openbrush::wrapper]
 pub type BetA0CoreRef = dyn PSP22 + BetA0CoreTrait;
@@ -13,17 +23,64 @@ pub type BetA0CoreRef = dyn PSP22 + BetA0CoreTrait;
 #[openbrush::trait_definition]
 pub trait BetA0CoreTrait: Ownable + Pausable {
     //Execute function
-    /// Function changes state
+    /// Function changes state - only PAUSER
     #[ink(message)]
     fn change_state(&mut self) -> Result<(), PausableError>;
 
-    /// tranfer token to pool
+    /// Pause or resume a single operation - only PAUSER
+    #[ink(message)]
+    #[modifiers(only_role(PAUSER))]
+    fn set_operation_paused(
+        &mut self,
+        operation: PausableOperation,
+        paused: bool,
+    ) -> Result<(), CoreError>;
+
+    /// Run the storage migration steps after an upgrade, the caller gets
+    /// the roles of a new deployment - only Owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn migrate(&mut self) -> Result<(), CoreError>;
+
+    /// Stop new bets for good and let players reclaim pending stakes - only Owner
     #[ink(message)]
     #[modifiers(only_owner)]
+    fn emergency_shutdown(&mut self) -> Result<(), CoreError>;
+
+    /// Reclaim the stake of a pending bet during emergency shutdown
+    #[ink(message)]
+    fn refund_bet(&mut self) -> Result<(), CoreError>;
+
+    /// Let bets in again after the circuit breaker tripped - only Owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn reset_circuit_breaker(&mut self) -> Result<(), CoreError>;
+
+    /// Pay out deferred wins up to what the block payout cap allows, the
+    /// rest stays deferred for a later block
+    #[ink(message)]
+    fn release_deferred_payout(&mut self, player: AccountId) -> Result<(), CoreError>;
+
+    /// Withdraw the whole caller balance
+    #[ink(message)]
+    fn claim(&mut self) -> Result<(), CoreError>;
+
+    /// Add the transferred native value to the caller balance, stakes and payouts are in the native token only
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(), CoreError>;
+
+    /// Withdraw amount from the caller balance
+    #[ink(message)]
+    fn withdraw(&mut self, amount: Balance) -> Result<(), CoreError>;
+
+    /// tranfer token to pool
+    #[ink(message)]
+    #[modifiers(only_role(TREASURER))]
     fn tranfer_token_to_pool(&mut self, pool: AccountId, amount: Balance) -> Result<(), CoreError>;
 
     /// reward token by bet pool
     #[ink(message)]
+    #[modifiers(only_role(FINALIZER))]
     fn reward_token_to_player(
         &mut self,
         player: AccountId,
@@ -32,85 +89,165 @@ pub trait BetA0CoreTrait: Ownable + Pausable {
 
     /// Function reward token
     #[ink(message)]
+    #[modifiers(only_role(FINALIZER))]
     fn reward_token(&mut self, player: AccountId, bet_amount: Balance) -> Result<(), CoreError>;
 
-    /// Withdraw Fees - only Owner
+    /// Withdraw Fees, escrowed stakes and balances owed to players stay - only TREASURER
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(TREASURER))]
     fn withdraw_fee(&mut self, value: Balance) -> Result<(), CoreError>;
 
-    /// Withdraw Token - only Owner
+    /// Withdraw Token - only TREASURER
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(TREASURER))]
     fn withdraw_token(&mut self, value: Balance) -> Result<(), CoreError>;
 
     // Set function
     /// Set min number over roll
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_min_number_over_roll(&mut self, min_over_number: u32) -> Result<(), CoreError>;
 
     /// Set max number over roll
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_max_number_over_roll(&mut self, max_over_number: u32) -> Result<(), CoreError>;
 
     /// Set min number under roll
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_min_number_under_roll(&mut self, min_under_number: u32) -> Result<(), CoreError>;
 
     /// Set max number under roll
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_max_number_under_roll(&mut self, max_under_number: u32) -> Result<(), CoreError>;
 
-    /// Set over_rates and discount rate - Only Owner 2 vectors same size
+    /// Set over_rates and discount rate - Only CONFIGURATOR 2 vectors same size
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_rates(&mut self, over_rates: Vec<u32>, under_rates: Vec<u32>) -> Result<(), CoreError>;
 
+    /// Set how a roll equal to bet_number is settled - only CONFIGURATOR
+    /// Fails once a timelock delay is set, use queue_change instead
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_tie_policy(&mut self, tie_policy: TiePolicy) -> Result<(), CoreError>;
+
     /// Set new psp22 address
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_bet_token_address(&mut self, bet_token_address: AccountId) -> Result<(), CoreError>;
 
     /// Set new token ratio
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_token_ratio(&mut self, token_ratio: u32) -> Result<(), CoreError>;
 
     /// Set max bet ratio
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_max_bet_ratio(&mut self, max_bet_ratio: u32) -> Result<(), CoreError>;
 
-    /// Set revenue_ratio
+    /// Set revenue_ratio, replaces the revenue split with reward_pool and general_pool
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_revenue_ratio(&mut self, revenue_ratio: u32) -> Result<(), CoreError>;
 
-    /// Set reward_pool
+    /// Set how losing stakes are split, shares in bps must sum to 10000 - only CONFIGURATOR
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_revenue_split(&mut self, revenue_split: Vec<RevenueShare>) -> Result<(), CoreError>;
+
+    /// Set reward_pool, Reward shares of the revenue split paid to the old
+    /// pool move to the new one, bets already placed keep their split
+    /// Fails once a timelock delay is set, use queue_change instead
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_reward_pool(&mut self, reward_pool: AccountId) -> Result<(), CoreError>;
 
-    /// Set max bet ratio
+    /// Set general_pool, Treasury shares of the revenue split paid to the
+    /// old pool move to the new one, bets already placed keep their split
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_general_pool(&mut self, general_pool: AccountId) -> Result<(), CoreError>;
 
     /// Set bet_pool
+    /// Fails once a timelock delay is set, use queue_change instead
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(CONFIGURATOR))]
     fn set_bet_pool(&mut self, bet_pool: AccountId) -> Result<(), CoreError>;
 
-    /// Set admin id
+    /// Set admin id, the FINALIZER role moves to the new admin
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(DEFAULT_ADMIN_ROLE))]
     fn set_admin_account(&mut self, admin_account: AccountId) -> Result<(), CoreError>;
 
+    /// Set grace period after which the bankroll can be withdrawn in emergency shutdown,
+    /// at least one day and fixed once shutdown starts
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_emergency_grace_period(
+        &mut self,
+        emergency_grace_period: Timestamp,
+    ) -> Result<(), CoreError>;
+
+    /// Set minimum bet - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_min_bet(&mut self, min_bet: Balance) -> Result<(), CoreError>;
+
+    /// Set maximum payout of a single bet, 0 for no cap - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_max_payout(&mut self, max_payout: Balance) -> Result<(), CoreError>;
+
+    /// Set maximum total payout per block, 0 for no cap - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_max_block_payout(&mut self, max_block_payout: Balance) -> Result<(), CoreError>;
+
+    /// Apply many settings at once, validated together - only CONFIGURATOR
+    /// Fails on timelocked fields once a timelock delay is set, use queue_change instead
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn update_config(&mut self, update: GameConfigUpdate) -> Result<(), CoreError>;
+
+    /// Set circuit breaker window in blocks and loss limit in bps of bankroll, 0 limit disables it - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_circuit_breaker(
+        &mut self,
+        window: BlockNumber,
+        max_loss_ratio: u32,
+    ) -> Result<(), CoreError>;
+
+    /// Set bet rate limits, 0 disables a limit - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_rate_limits(&mut self, rate_limits: RateLimits) -> Result<(), CoreError>;
+
+    /// Send winnings of bets paid with a transfer straight to players and credit their balance only if the transfer fails - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_auto_push(&mut self, auto_push: bool) -> Result<(), CoreError>;
+
     // Get Function
+    /// get the whole game configuration in one call
+    #[ink(message)]
+    fn get_config(&self) -> GameConfig;
+
+    /// Quote a bet of the caller without placing it, same checks and arithmetic as play and finalize
+    /// including a promo boost waiting for the caller's next bet
+    #[ink(message)]
+    fn quote(&self, is_over: u8, bet_number: u32, amount: Balance) -> BetQuote;
+
     /// get min number over roll
     #[ink(message)]
     fn get_min_number_over_roll(&self) -> u32;
@@ -135,6 +272,14 @@ pub trait BetA0CoreTrait: Ownable + Pausable {
     #[ink(message)]
     fn get_revenue_ratio(&self) -> u32;
 
+    /// get id of the current revenue split
+    #[ink(message)]
+    fn get_revenue_split_id(&self) -> u32;
+
+    /// get revenue split by id, bets settle with the split of their play
+    #[ink(message)]
+    fn get_revenue_split(&self, revenue_split_id: u32) -> Vec<RevenueShare>;
+
     /// get reward pool
     #[ink(message)]
     fn get_reward_pool(&self) -> AccountId;
@@ -185,6 +330,111 @@ pub trait BetA0CoreTrait: Ownable + Pausable {
     /// get bet
     #[ink(message)]
     fn get_bet(&self, player: AccountId) -> Option<BetInformation>;
+
+    /// get storage version
+    #[ink(message)]
+    fn get_storage_version(&self) -> u32;
+
+    /// Is operation paused by its own switch
+    #[ink(message)]
+    fn is_operation_paused(&self, operation: PausableOperation) -> bool;
+
+    /// get operations paused by their own switch
+    #[ink(message)]
+    fn get_paused_operations(&self) -> Vec<PausableOperation>;
+
+    /// Is emergency shutdown
+    #[ink(message)]
+    fn is_emergency_shutdown(&self) -> bool;
+
+    /// get emergency shutdown time
+    #[ink(message)]
+    fn get_emergency_shutdown_at(&self) -> Option<Timestamp>;
+
+    /// get emergency grace period
+    #[ink(message)]
+    fn get_emergency_grace_period(&self) -> Timestamp;
+
+    /// get number of pending bets, bets placed before storage version 1
+    /// are not counted
+    #[ink(message)]
+    fn get_pending_bets(&self) -> u32;
+
+    /// get total stake of pending bets
+    #[ink(message)]
+    fn get_escrowed_stakes(&self) -> Balance;
+
+    /// get minimum bet
+    #[ink(message)]
+    fn get_min_bet(&self) -> Balance;
+
+    /// get maximum payout of a single bet
+    #[ink(message)]
+    fn get_max_payout(&self) -> Balance;
+
+    /// get maximum total payout per block
+    #[ink(message)]
+    fn get_max_block_payout(&self) -> Balance;
+
+    /// get total paid out in the current block
+    #[ink(message)]
+    fn get_block_payout(&self) -> Balance;
+
+    /// get deferred payout of player
+    #[ink(message)]
+    fn get_deferred_payout(&self, player: AccountId) -> Balance;
+
+    /// get balance of player, deposits and unclaimed winnings and refunds
+    #[ink(message)]
+    fn get_claimable(&self, player: AccountId) -> Balance;
+
+    /// get balance of all players
+    #[ink(message)]
+    fn get_total_claimable(&self) -> Balance;
+
+    /// is auto push enabled
+    #[ink(message)]
+    fn is_auto_push(&self) -> bool;
+
+    /// is circuit breaker tripped
+    #[ink(message)]
+    fn is_circuit_breaker_tripped(&self) -> bool;
+
+    /// get circuit breaker window in blocks
+    #[ink(message)]
+    fn get_circuit_breaker_window(&self) -> BlockNumber;
+
+    /// get circuit breaker loss limit in bps of bankroll
+    #[ink(message)]
+    fn get_circuit_breaker_max_loss_ratio(&self) -> u32;
+
+    /// get net house loss over the last circuit breaker window blocks
+    #[ink(message)]
+    fn get_window_house_loss(&self) -> Balance;
+
+    /// get bet rate limits
+    #[ink(message)]
+    fn get_rate_limits(&self) -> RateLimits;
+
+    /// get bet rate counters of player
+    #[ink(message)]
+    fn get_player_bet_rate(&self, player: AccountId) -> PlayerBetRate;
+
+    /// get number of bets placed in the current block
+    #[ink(message)]
+    fn get_block_bets(&self) -> u32;
+
+    /// get tie policy
+    #[ink(message)]
+    fn get_tie_policy(&self) -> TiePolicy;
+
+    /// get return to player of a bet in bps under the current rates and tie policy
+    #[ink(message)]
+    fn get_rtp(&self, is_over: u8, bet_number: u32) -> u32;
+
+    /// get payout of every allowed bet number on one side
+    #[ink(message)]
+    fn get_payout_table(&self, is_over: u8) -> Vec<PayoutEntry>;
 }
 
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -194,6 +444,31 @@ pub enum CoreError {
     PSP22Error(PSP22Error),
     PausableError(PausableError),
     OwnableError(OwnableError),
+    AccessControlError(AccessControlError),
+    EligibilityError(EligibilityError),
+    InvalidConfig(ConfigError),
+}
+
+/// Configuration invariant broken by a change, the change is reverted
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ConfigError {
+    ZeroTokenRatio,
+    ZeroMaxBetRatio,
+    InvalidRevenueRatio,
+    InvalidOverRange,
+    InvalidUnderRange,
+    InvalidRatesLength,
+    /// No rate for an allowed (is_over, bet_number)
+    MissingRate(u8, u32),
+    /// Return to player above 100% for (is_over, bet_number)
+    RateAboveFair(u8, u32),
+    ZeroAddress(ConfigParam),
+    InvalidPayoutCaps,
+    InvalidLossRatio,
+    InvalidTieRefund,
+    InvalidRevenueSplit,
+    RevenueSplitNotFull,
 }
 
 impl From<PausableError> for CoreError {
@@ -213,3 +488,21 @@ impl From<PSP22Error> for CoreError {
         CoreError::PSP22Error(error)
     }
 }
+
+impl From<AccessControlError> for CoreError {
+    fn from(access: AccessControlError) -> Self {
+        CoreError::AccessControlError(access)
+    }
+}
+
+impl From<EligibilityError> for CoreError {
+    fn from(error: EligibilityError) -> Self {
+        CoreError::EligibilityError(error)
+    }
+}
+
+impl From<ConfigError> for CoreError {
+    fn from(error: ConfigError) -> Self {
+        CoreError::InvalidConfig(error)
+    }
+}
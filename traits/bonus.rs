@@ -0,0 +1,51 @@
+use openbrush::{
+    contracts::access_control::*,
+    modifiers,
+    traits::{AccountId, Balance, Timestamp},
+};
+
+use crate::{
+    impls::{beta0_core::PROMOTER, bonus::BonusAccount},
+    traits::beta0_core::CoreError,
+};
+
+#[openbrush::wrapper]
+pub type BonusRef = dyn BonusTrait;
+
+#[openbrush::trait_definition]
+pub trait BonusTrait {
+    // Execute function
+    /// Grant bonus credits to player, they must wager wagering_required in total
+    /// before winnings of bonus bets unlock - only PROMOTER
+    #[ink(message)]
+    #[modifiers(only_role(PROMOTER))]
+    fn grant_bonus(
+        &mut self,
+        player: AccountId,
+        credits: Balance,
+        wagering_required: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), CoreError>;
+
+    /// Remove the bonus of player, credits and locked winnings are forfeited - only PROMOTER
+    #[ink(message)]
+    #[modifiers(only_role(PROMOTER))]
+    fn revoke_bonus(&mut self, player: AccountId) -> Result<(), CoreError>;
+
+    /// Forfeit an expired bonus, anyone may call
+    #[ink(message)]
+    fn forfeit_expired_bonus(&mut self, player: AccountId) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get bonus of player, empty once expired
+    #[ink(message)]
+    fn get_bonus(&self, player: AccountId) -> BonusAccount;
+
+    /// get bonus credits of all players
+    #[ink(message)]
+    fn get_total_bonus_credits(&self) -> Balance;
+
+    /// get locked bonus winnings of all players
+    #[ink(message)]
+    fn get_total_bonus_locked(&self) -> Balance;
+}
@@ -0,0 +1,91 @@
+use openbrush::{
+    contracts::access_control::*,
+    modifiers,
+    traits::{AccountId, Timestamp},
+};
+
+use crate::{
+    impls::{beta0_core::CONFIGURATOR, eligibility::Attestation},
+    traits::beta0_core::CoreError,
+};
+
+#[openbrush::wrapper]
+pub type EligibilityRef = dyn EligibilityTrait;
+
+#[openbrush::trait_definition]
+pub trait EligibilityTrait {
+    // Execute function
+    /// Store a signed attestation for its account, one expiring no later than the stored one changes nothing
+    #[ink(message)]
+    fn submit_attestation(
+        &mut self,
+        attestation: Attestation,
+        signature: [u8; 65],
+    ) -> Result<(), CoreError>;
+
+    // Set function
+    /// Add or remove account from the deny list - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_denied(&mut self, account: AccountId, denied: bool) -> Result<(), CoreError>;
+
+    /// Add or remove account from the allow list - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_allowed(&mut self, account: AccountId, allowed: bool) -> Result<(), CoreError>;
+
+    /// Only allow listed accounts can play while enabled - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_allow_list_enabled(&mut self, enabled: bool) -> Result<(), CoreError>;
+
+    /// Require a valid attestation to play - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_attestation_required(&mut self, required: bool) -> Result<(), CoreError>;
+
+    /// Set compressed ECDSA key signing attestations, revokes the attestations of the previous key - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_attestation_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError>;
+
+    // Get Function
+    /// Check account may play, with the reason when it may not
+    #[ink(message)]
+    fn check_eligibility(&self, account: AccountId) -> Result<(), EligibilityError>;
+
+    /// Is account on the deny list
+    #[ink(message)]
+    fn is_denied(&self, account: AccountId) -> bool;
+
+    /// Is account on the allow list
+    #[ink(message)]
+    fn is_allowed(&self, account: AccountId) -> bool;
+
+    /// Is allow list enabled
+    #[ink(message)]
+    fn is_allow_list_enabled(&self) -> bool;
+
+    /// Is attestation required
+    #[ink(message)]
+    fn is_attestation_required(&self) -> bool;
+
+    /// get attestation signer
+    #[ink(message)]
+    fn get_attestation_signer(&self) -> Option<[u8; 33]>;
+
+    /// get expiry of the account attestation
+    #[ink(message)]
+    fn get_attestation_expiry(&self, account: AccountId) -> Option<Timestamp>;
+}
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum EligibilityError {
+    Denied,
+    NotAllowListed,
+    AttestationRequired,
+    AttestationExpired,
+    AttestationSignerNotSet,
+    InvalidAttestationSignature,
+}
@@ -0,0 +1,94 @@
+use openbrush::{
+    contracts::traits::{ownable::*, pausable::*},
+    modifiers,
+    traits::{AccountId, String, Timestamp},
+};
+
+use crate::impls::guarded_ownable::OwnerRecovery;
+
+#[openbrush::wrapper]
+pub type GuardedOwnableRef = dyn GuardedOwnableTrait;
+
+#[openbrush::trait_definition]
+pub trait GuardedOwnableTrait: Ownable {
+    // Execute function
+    /// Propose a new owner, it takes effect once accepted, not while a recovery is pending - only Owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn propose_owner(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError>;
+
+    /// Accept ownership, not while a recovery is pending - only pending owner
+    #[ink(message)]
+    fn accept_ownership(&mut self) -> Result<(), GuardedOwnableError>;
+
+    /// Set or remove the guardian, not while a recovery is pending - only Owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<(), GuardedOwnableError>;
+
+    /// Set delay between starting and completing a recovery, one to thirty
+    /// days - only Owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_recovery_delay(&mut self, recovery_delay: Timestamp) -> Result<(), GuardedOwnableError>;
+
+    /// Pause the contract - only guardian
+    #[ink(message)]
+    fn guardian_pause(&mut self) -> Result<(), GuardedOwnableError>;
+
+    /// Start moving ownership to new_owner after the recovery delay - only guardian
+    #[ink(message)]
+    fn start_recovery(&mut self, new_owner: AccountId) -> Result<(), GuardedOwnableError>;
+
+    /// Cancel a started recovery - only guardian
+    #[ink(message)]
+    fn cancel_recovery(&mut self) -> Result<(), GuardedOwnableError>;
+
+    /// Transfer ownership once the recovery delay has passed - only guardian
+    #[ink(message)]
+    fn complete_recovery(&mut self) -> Result<(), GuardedOwnableError>;
+
+    // Get Function
+    /// get pending owner
+    #[ink(message)]
+    fn get_pending_owner(&self) -> Option<AccountId>;
+
+    /// get guardian
+    #[ink(message)]
+    fn get_guardian(&self) -> Option<AccountId>;
+
+    /// get recovery delay
+    #[ink(message)]
+    fn get_recovery_delay(&self) -> Timestamp;
+
+    /// get started recovery
+    #[ink(message)]
+    fn get_recovery(&self) -> Option<OwnerRecovery>;
+}
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GuardedOwnableError {
+    Custom(String),
+    OwnableError(OwnableError),
+    PausableError(PausableError),
+    CallerIsNotPendingOwner,
+    CallerIsNotGuardian,
+    RecoveryNotStarted,
+    RecoveryNotReady,
+    RecoveryPending,
+    RecoveryDelayTooShort,
+    RecoveryDelayTooLong,
+}
+
+impl From<OwnableError> for GuardedOwnableError {
+    fn from(access: OwnableError) -> Self {
+        GuardedOwnableError::OwnableError(access)
+    }
+}
+
+impl From<PausableError> for GuardedOwnableError {
+    fn from(access: PausableError) -> Self {
+        GuardedOwnableError::PausableError(access)
+    }
+}
@@ -1,2 +1,10 @@
 pub mod bet_token;
-pub mod beta0_core;
\ No newline at end of file
+pub mod beta0_core;
+pub mod bonus;
+pub mod eligibility;
+pub mod guarded_ownable;
+pub mod promo;
+pub mod relay;
+pub mod responsible_gaming;
+pub mod session;
+pub mod timelock;
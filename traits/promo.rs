@@ -0,0 +1,42 @@
+use openbrush::{contracts::access_control::*, modifiers, traits::AccountId};
+
+use crate::{
+    impls::{beta0_core::CONFIGURATOR, promo::PromoCode},
+    traits::beta0_core::CoreError,
+};
+
+#[openbrush::wrapper]
+pub type PromoRef = dyn PromoTrait;
+
+#[openbrush::trait_definition]
+pub trait PromoTrait {
+    // Execute function
+    /// Credit the reward of a promo code signed by the promo signer to the
+    /// caller: BET tokens, bonus credits or a multiplier boost on the next bet
+    /// A boost is rejected once the caller's boosts pass MAX_MULTIPLIER_BOOST
+    #[ink(message)]
+    fn redeem_promo(&mut self, code: PromoCode, signature: [u8; 65]) -> Result<(), CoreError>;
+
+    // Set function
+    /// Set compressed ECDSA key signing promo codes - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_promo_signer(&mut self, signer: Option<[u8; 33]>) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get promo signer
+    #[ink(message)]
+    fn get_promo_signer(&self) -> Option<[u8; 33]>;
+
+    /// get redemptions of a promo code by all accounts
+    #[ink(message)]
+    fn get_promo_redemptions(&self, code_id: u32) -> u32;
+
+    /// get redemptions of a promo code by account
+    #[ink(message)]
+    fn get_account_promo_redemptions(&self, code_id: u32, account: AccountId) -> u32;
+
+    /// get multiplier boost waiting for the next bet of player
+    #[ink(message)]
+    fn get_multiplier_boost(&self, player: AccountId) -> u32;
+}
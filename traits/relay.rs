@@ -0,0 +1,20 @@
+use openbrush::traits::AccountId;
+
+use crate::traits::beta0_core::CoreError;
+
+#[openbrush::wrapper]
+pub type RelayRef = dyn RelayTrait;
+
+#[openbrush::trait_definition]
+pub trait RelayTrait {
+    // Execute function
+    /// Use up the next nonce of the caller, a signed bet with it can no
+    /// longer be relayed
+    #[ink(message)]
+    fn invalidate_nonce(&mut self) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get the nonce the next signed bet of player must use
+    #[ink(message)]
+    fn get_nonce(&self, player: AccountId) -> u64;
+}
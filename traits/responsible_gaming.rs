@@ -0,0 +1,58 @@
+use openbrush::{
+    contracts::access_control::*,
+    modifiers,
+    traits::{AccountId, Timestamp},
+};
+
+use crate::{
+    impls::{
+        beta0_core::CONFIGURATOR,
+        responsible_gaming::{PendingLimits, PlayerActivity, PlayerLimits},
+    },
+    traits::beta0_core::CoreError,
+};
+
+#[openbrush::wrapper]
+pub type ResponsibleGamingRef = dyn ResponsibleGamingTrait;
+
+#[openbrush::trait_definition]
+pub trait ResponsibleGamingTrait {
+    // Execute function
+    /// Set caller limits, lower limits apply now and raised ones after the increase delay
+    #[ink(message)]
+    fn set_limits(&mut self, limits: PlayerLimits) -> Result<(), CoreError>;
+
+    /// Exclude caller from play for duration, an exclusion can never be shortened
+    #[ink(message)]
+    fn self_exclude(&mut self, duration: Timestamp) -> Result<(), CoreError>;
+
+    // Set function
+    /// Set delay before raised limits apply, at least one day - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn set_limit_increase_delay(
+        &mut self,
+        limit_increase_delay: Timestamp,
+    ) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get limits in force for player
+    #[ink(message)]
+    fn get_limits(&self, player: AccountId) -> PlayerLimits;
+
+    /// get raised limits waiting for the increase delay
+    #[ink(message)]
+    fn get_pending_limits(&self, player: AccountId) -> Option<PendingLimits>;
+
+    /// get player activity in the current periods
+    #[ink(message)]
+    fn get_activity(&self, player: AccountId) -> PlayerActivity;
+
+    /// get end of player self exclusion
+    #[ink(message)]
+    fn get_self_excluded_until(&self, player: AccountId) -> Option<Timestamp>;
+
+    /// get limit increase delay
+    #[ink(message)]
+    fn get_limit_increase_delay(&self) -> Timestamp;
+}
@@ -0,0 +1,41 @@
+use openbrush::traits::{AccountId, Balance, Timestamp};
+
+use crate::{impls::session::SessionKey, traits::beta0_core::CoreError};
+
+#[openbrush::wrapper]
+pub type SessionRef = dyn SessionTrait;
+
+#[openbrush::trait_definition]
+pub trait SessionTrait {
+    // Execute function
+    /// Propose key to bet for the caller from the caller balance with
+    /// `play_with_session_key`, until expires_at and for at most
+    /// max_total_stake in total. The key must accept it with
+    /// `accept_session_key`
+    #[ink(message)]
+    fn register_session_key(
+        &mut self,
+        key: AccountId,
+        expires_at: Timestamp,
+        max_total_stake: Balance,
+        allow_over: bool,
+        allow_under: bool,
+    ) -> Result<(), CoreError>;
+
+    /// Accept as the key the registration proposed by player
+    #[ink(message)]
+    fn accept_session_key(&mut self, player: AccountId) -> Result<(), CoreError>;
+
+    /// Revoke a session key of the caller, pending or accepted
+    #[ink(message)]
+    fn revoke_session_key(&mut self, key: AccountId) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get the terms of a session key
+    #[ink(message)]
+    fn get_session_key(&self, key: AccountId) -> Option<SessionKey>;
+
+    /// get the terms player proposed for a session key
+    #[ink(message)]
+    fn get_pending_session_key(&self, key: AccountId, player: AccountId) -> Option<SessionKey>;
+}
@@ -0,0 +1,45 @@
+use ink::prelude::vec::Vec;
+use openbrush::{contracts::access_control::*, modifiers, traits::Timestamp};
+
+use crate::{
+    impls::{
+        beta0_core::CONFIGURATOR,
+        timelock::{PendingChange, TimelockAction},
+    },
+    traits::beta0_core::CoreError,
+};
+
+#[openbrush::wrapper]
+pub type TimelockRef = dyn TimelockTrait;
+
+#[openbrush::trait_definition]
+pub trait TimelockTrait {
+    // Execute function
+    /// Queue a parameter change, eta must be at least the delay from now - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn queue_change(&mut self, action: TimelockAction, eta: Timestamp) -> Result<u64, CoreError>;
+
+    /// Apply a queued change once its eta has passed - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn execute_change(&mut self, id: u64) -> Result<(), CoreError>;
+
+    /// Drop a queued change - only CONFIGURATOR
+    #[ink(message)]
+    #[modifiers(only_role(CONFIGURATOR))]
+    fn cancel_change(&mut self, id: u64) -> Result<(), CoreError>;
+
+    // Get Function
+    /// get timelock delay
+    #[ink(message)]
+    fn get_timelock_delay(&self) -> Timestamp;
+
+    /// get queued change
+    #[ink(message)]
+    fn get_pending_change(&self, id: u64) -> Option<PendingChange>;
+
+    /// get all queued changes
+    #[ink(message)]
+    fn get_pending_changes(&self) -> Vec<PendingChange>;
+}